@@ -0,0 +1,63 @@
+//!
+//! Compares the bounds checked bit accessors against their unchecked counterparts in a
+//! tight loop over every bit of a slice.
+//! 
+//! Run with `cargo bench --bench bit_access`.
+//! 
+
+use bitmap::prelude::*;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const BUFFER_SIZE: usize = 1 << 14;
+const ITERATIONS: u32 = 100;
+
+fn time<F: FnMut() -> usize>(mut f: F) -> (Duration, usize) {
+    let mut result = 0;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        result = black_box(f());
+    }
+
+    (start.elapsed() / ITERATIONS, result)
+}
+
+fn main() {
+    let mut buffer = vec![0usize; BUFFER_SIZE];
+    let bit_count = BUFFER_SIZE * (usize::BITS as usize) - 5;
+
+    let (checked_set_time, _) = time(|| {
+        let mut slice = BitmapSliceMut::new(&mut buffer, 5..(bit_count + 5));
+        for bit_index in (0..bit_count).step_by(3) {
+            slice.toggle_bit(black_box(bit_index));
+        }
+
+        0
+    });
+
+    let (unchecked_set_time, _) = time(|| {
+        let mut slice = BitmapSliceMut::new(&mut buffer, 5..(bit_count + 5));
+        for bit_index in (0..bit_count).step_by(3) {
+            unsafe { slice.toggle_bit_unchecked(black_box(bit_index)) };
+        }
+
+        0
+    });
+
+    let slice = BitmapSlice::new(&buffer, 5..(bit_count + 5));
+    let (checked_get_time, checked_count) = time(|| {
+        (0..bit_count).filter(|bit_index| slice.get_bit(black_box(*bit_index))).count()
+    });
+
+    let (unchecked_get_time, unchecked_count) = time(|| {
+        (0..bit_count).filter(|bit_index| unsafe { slice.get_bit_unchecked(black_box(*bit_index)) }).count()
+    });
+
+    assert_eq!(checked_count, unchecked_count);
+
+    println!("toggle_bit (checked):             {:?}", checked_set_time);
+    println!("toggle_bit_unchecked:             {:?}", unchecked_set_time);
+    println!("get_bit (checked):                {:?}", checked_get_time);
+    println!("get_bit_unchecked:                {:?}", unchecked_get_time);
+}
@@ -0,0 +1,46 @@
+//!
+//! Measures `find_next_set_from` on a large, sparse byte-backed bitmap with a single set
+//! bit near the end, against a slot-at-a-time scan of the same buffer.
+//! 
+//! Run with `cargo bench --bench find_next_set`.
+//! 
+
+use bitmap::prelude::*;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const BUFFER_SIZE: usize = 1 << 20;
+const ITERATIONS: u32 = 100;
+
+fn time<F: FnMut() -> Option<usize>>(mut f: F) -> (Duration, Option<usize>) {
+    let mut result = None;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        result = black_box(f());
+    }
+
+    (start.elapsed() / ITERATIONS, result)
+}
+
+fn main() {
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+    buffer[BUFFER_SIZE - 3] = 0b0001_0000;
+
+    let slice = BitmapSlice::new(&buffer, 3..(BUFFER_SIZE * 8));
+
+    let (chunked_time, chunked_result) = time(|| black_box(&slice).find_next_set_from(0));
+    let (scalar_time, scalar_result) = time(|| {
+        black_box(&slice)
+            .word_iter_all()
+            .enumerate()
+            .find(|(_, slot_contents)| *slot_contents != 0)
+            .map(|(slot, slot_contents)| (slot * 8) + (slot_contents.trailing_zeros() as usize) - 3)
+    });
+
+    assert_eq!(chunked_result, scalar_result);
+
+    println!("find_next_set_from (chunked):   {:?}", chunked_time);
+    println!("slot at a time scan (scalar):   {:?}", scalar_time);
+    println!("speedup:                        {:.1}x", scalar_time.as_secs_f64() / chunked_time.as_secs_f64());
+}
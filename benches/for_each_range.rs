@@ -0,0 +1,62 @@
+//!
+//! Compares the callback based range walkers against collecting the same ranges through
+//! the range iterators.
+//! 
+//! Run with `cargo bench --bench for_each_range`.
+//! 
+
+use bitmap::prelude::*;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const BUFFER_SIZE: usize = 1 << 14;
+const ITERATIONS: u32 = 100;
+
+fn time<F: FnMut() -> usize>(mut f: F) -> (Duration, usize) {
+    let mut result = 0;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        result = black_box(f());
+    }
+
+    (start.elapsed() / ITERATIONS, result)
+}
+
+fn main() {
+    let buffer: Vec<usize> = (0..BUFFER_SIZE).map(|index| index.wrapping_mul(0x9E37_79B9_7F4A_7C15usize)).collect();
+    let bit_count = BUFFER_SIZE * (usize::BITS as usize) - 5;
+    let slice = BitmapSlice::new(&buffer, 5..(bit_count + 5));
+
+    let (iter_set_time, iter_set_total) = time(|| {
+        let mut total = 0;
+        slice.range_iter().for_each(|(range_start, range_count)| total += range_start ^ range_count);
+        total
+    });
+
+    let (for_each_set_time, for_each_set_total) = time(|| {
+        let mut total = 0;
+        slice.for_each_set_range(|range_start, range_count| total += range_start ^ range_count);
+        total
+    });
+
+    let (iter_clear_time, iter_clear_total) = time(|| {
+        let mut total = 0;
+        slice.clear_range_iter().for_each(|(range_start, range_count)| total += range_start ^ range_count);
+        total
+    });
+
+    let (for_each_clear_time, for_each_clear_total) = time(|| {
+        let mut total = 0;
+        slice.for_each_clear_range(|range_start, range_count| total += range_start ^ range_count);
+        total
+    });
+
+    assert_eq!(iter_set_total, for_each_set_total);
+    assert_eq!(iter_clear_total, for_each_clear_total);
+
+    println!("range_iter().for_each:            {:?}", iter_set_time);
+    println!("for_each_set_range:               {:?}", for_each_set_time);
+    println!("clear_range_iter().for_each:      {:?}", iter_clear_time);
+    println!("for_each_clear_range:             {:?}", for_each_clear_time);
+}
@@ -1,8 +1,17 @@
 
 mod api;
+mod atomic;
+#[cfg(feature = "alloc")]
+mod journaled;
 mod traits;
 
-#[cfg(test)]
+#[cfg(feature = "serde")]
+mod serialize;
+
+#[cfg(all(test, feature = "std"))]
 mod test;
 
 pub use self::api::Bitmap;
+pub use self::atomic::AtomicBitmap;
+#[cfg(feature = "alloc")]
+pub use self::journaled::JournaledBitmap;
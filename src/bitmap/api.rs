@@ -1,10 +1,26 @@
 
-use crate::slice::{BitmapSlice, BitmapSliceIter, BitmapSliceMut, BitmapSliceRangeIter};
-use crate::store::BitStore;
+use crate::error::BitmapError;
+#[cfg(feature = "alloc")]
+use crate::error::{DecodeError, ParseError};
+use crate::slice::{
+    BitmapClearIter,
+    BitmapClearRangeIter,
+    BitmapSlice,
+    BitmapSliceIter,
+    BitmapSliceMut,
+    BitmapSliceRangeIter,
+    BitmapSliceRevIter,
+    BitmapSliceWordIter
+};
+use crate::store::{BitOrder, BitStore, Lsb0};
 use crate::traits::{BitmapOpts, BitmapOptsMut};
 
-use std::marker::PhantomData;
-use std::ops::Range;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ptr::NonNull;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 
 ///
 /// Implements a bitmap over any type that can be converted to a reference to a slice.
@@ -16,35 +32,143 @@ use std::ops::Range;
 /// This allows for a Bitmap instance to grow or shrink if the underlying storage
 /// supports a dynamic size.
 /// 
-pub struct Bitmap<S: ?Sized, B = usize> {
-    pub(super) _bs: PhantomData<*const B>,
+/// The logical length of a bitmap does not need to be a multiple of the size of the
+/// individual elements. Any bits in the final element past the logical length are
+/// padding and are ignored by all operations.
+/// 
+/// A bitmap is [Send] and [Sync] whenever its backing storage is.
+/// 
+pub struct Bitmap<S: ?Sized, B = usize, O = Lsb0> {
+    pub(super) _bs: PhantomData<fn() -> (B, O)>,
+    pub(super) bit_count: usize,
     pub(super) bitmap_store: S
 }
 
-impl<S, B> Bitmap<S, B> {
+impl<S, B, O> Bitmap<S, B, O> {
+
+    ///
+    /// Consumes this bitmap instance and returns the underlying storage.
+    /// 
+    pub fn into_inner(self) -> S {
+        self.bitmap_store
+    }
+
+}
+
+impl<S: AsRef<[B]>, B: BitStore> Bitmap<S, B> {
 
     ///
-    /// Creates a new bitmap with the provided backing store.
+    /// Creates a new bitmap with the provided backing store. The bitmap covers every
+    /// bit in the backing store. The size of the backing store is validated when the
+    /// bitmap is accessed, as with [as_slice](Bitmap::as_slice).
     /// 
     pub fn new(bitmap_store: S) -> Self {
-        Bitmap { _bs: PhantomData::default(), bitmap_store }
+        Self::with_order(bitmap_store, Lsb0)
     }
 
     ///
-    /// Consumes this bitmap instance and returns the underlying storage.
+    /// Creates a new bitmap with the provided backing store covering only the first
+    /// `bit_count` bits of the backing store.
     /// 
-    pub fn into_inner(self) -> S {
-        self.bitmap_store
+    /// # Panics
+    /// 
+    /// Panics if `bit_count` is larger than the number of bits in the backing storage.
+    /// 
+    pub fn with_bit_count(bitmap_store: S, bit_count: usize) -> Self {
+        Self::with_order_and_bit_count(bitmap_store, bit_count, Lsb0)
+    }
+
+}
+
+impl<S: AsRef<[B]>, B: BitStore, O: BitOrder> Bitmap<S, B, O> {
+
+    ///
+    /// Creates a new bitmap with the provided backing store that numbers the bits of each
+    /// slot in the bit order `O`. Otherwise this is the same as [new](Bitmap::new).
+    /// 
+    /// # Examples
+    /// ```
+    /// # use bitmap::prelude::*;
+    /// let mut bitmap = Bitmap::with_order([0u8; 2], Msb0);
+    /// bitmap.set_bit(0);
+    /// bitmap.set_bit(9);
+    /// assert_eq!(bitmap.into_inner(), [0b1000_0000, 0b0100_0000]);
+    /// ```
+    /// 
+    pub fn with_order(bitmap_store: S, _order: O) -> Self {
+        let bit_count = bitmap_store.as_ref().len().saturating_mul(B::BIT_COUNT);
+        Bitmap { _bs: PhantomData, bit_count, bitmap_store }
+    }
+
+    ///
+    /// Creates a new bitmap with the provided backing store that numbers the bits of each
+    /// slot in the bit order `O`. Otherwise this is the same as [with_bit_count](Bitmap::with_bit_count).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `bit_count` is larger than the number of bits in the backing storage.
+    /// 
+    pub fn with_order_and_bit_count(bitmap_store: S, bit_count: usize, order: O) -> Self {
+        let mut bitmap = Self::with_order(bitmap_store, order);
+        if bit_count > bitmap.bit_count {
+            panic!("Invalid bit count {} for buffer of {} bits", bit_count, bitmap.bit_count);
+        }
+
+        bitmap.bit_count = bit_count;
+        bitmap
+    }
+
+}
+
+impl<B: BitStore, O: BitOrder, const N: usize> Bitmap<[B; N], B, O> {
+
+    ///
+    /// Creates a new bitmap over an array of `N` zeroed slots covering every bit in the
+    /// array. This is a `const fn`, so it can initialize bitmaps in `const` and `static`
+    /// context. Combined with [bits_to_slots](crate::store::bits_to_slots), the array size
+    /// can be derived from the required number of bits at compile time.
+    /// 
+    /// # Examples
+    /// ```
+    /// # use bitmap::prelude::*;
+    /// # use bitmap::store::bits_to_slots;
+    /// const EMPTY: Bitmap<[u64; bits_to_slots::<u64>(100)], u64> = Bitmap::zeroed();
+    /// 
+    /// let mut bitmap = EMPTY;
+    /// bitmap.set_bit(99);
+    /// assert_eq!(bitmap.size(), 128);
+    /// assert_eq!(bitmap.find_first_set(), Some(99));
+    /// ```
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `N` is larger than [MAXIMUM_BUFFER_SIZE](crate::bitmap::Bitmap::MAXIMUM_BUFFER_SIZE)
+    /// 
+    pub const fn zeroed() -> Self {
+        if N > Self::MAXIMUM_BUFFER_SIZE {
+            panic!("Bitmap buffer is too large");
+        }
+
+        Bitmap { _bs: PhantomData, bit_count: N * B::BIT_COUNT, bitmap_store: [B::ZERO; N] }
     }
 
 }
 
-impl<S: AsRef<[B]> + ?Sized, B: BitStore> Bitmap<S, B> {
+impl<S: AsRef<[B]> + ?Sized, B: BitStore, O: BitOrder> Bitmap<S, B, O> {
 
     ///
     /// A const containing the maximum supported length of the backing bitmap storage.
     /// 
     pub const MAXIMUM_BUFFER_SIZE: usize = usize::MAX / B::BIT_COUNT;
+
+    fn validate_buffer(buffer_len: usize, bit_count: usize) {
+        if buffer_len > Self::MAXIMUM_BUFFER_SIZE {
+            panic!("Bitmap buffer is too large ({} > {})", buffer_len, Self::MAXIMUM_BUFFER_SIZE);
+
+        } else if bit_count > (buffer_len * B::BIT_COUNT) {
+            panic!("Bitmap buffer is too small ({} bits < {} bits)", buffer_len * B::BIT_COUNT, bit_count);
+        }
+    }
     
     ///
     /// Converts this bitmap into a [BitmapSlice](crate::slice::BitmapSlice) over the backing
@@ -54,37 +178,483 @@ impl<S: AsRef<[B]> + ?Sized, B: BitStore> Bitmap<S, B> {
     /// 
     /// Panics if the backing storage is larger than [MAXIMUM_BUFFER_SIZE](crate::bitmap::Bitmap::MAXIMUM_BUFFER_SIZE)
     /// 
-    pub fn as_slice(&self) -> BitmapSlice<B> {
+    pub fn as_slice(&self) -> BitmapSlice<'_, B, O> {
         let buffer = self.bitmap_store.as_ref();
-        if buffer.len() > Self::MAXIMUM_BUFFER_SIZE {
-            panic!("Bitmap buffer is too large ({} > {})", buffer.len(), Self::MAXIMUM_BUFFER_SIZE);
-        }
+        Self::validate_buffer(buffer.len(), self.bit_count);
 
         unsafe {
-            BitmapSlice::new_unchecked(buffer, 0, buffer.len() * B::BIT_COUNT)
+            BitmapSlice::from_raw_parts(NonNull::new_unchecked(buffer.as_ptr() as *mut _), 0, self.bit_count)
         }
     }
 
+    ///
+    /// Returns the total number of bits in the backing storage. This is always at least
+    /// the logical length of this bitmap.
+    /// 
+    pub fn bit_capacity(&self) -> usize {
+        self.bitmap_store.as_ref().len() * B::BIT_COUNT
+    }
+
+    ///
+    /// Sets the logical length of this bitmap to `new_len` bits without changing the backing
+    /// storage. Any bits exposed by growing the length keep their current contents. If `new_len`
+    /// is larger than [bit_capacity](Bitmap::bit_capacity), the length is left unchanged and a
+    /// [BitmapError::LengthExceedsCapacity](crate::error::BitmapError::LengthExceedsCapacity)
+    /// is returned.
+    /// 
+    pub fn set_bit_len(&mut self, new_len: usize) -> Result<(), BitmapError> {
+        let capacity = self.bit_capacity();
+        if new_len > capacity {
+            return Err(BitmapError::LengthExceedsCapacity { bit_count: new_len, capacity });
+        }
+
+        self.bit_count = new_len;
+        Ok(())
+    }
+
+    ///
+    /// This routine returns `true` if the logical length of this bitmap is a multiple of
+    /// the size of the backing elements, otherwise returns false. When this returns false,
+    /// the final backing element contains padding bits that are not part of this bitmap.
+    /// 
+    pub fn is_length_word_aligned(&self) -> bool {
+        (self.bit_count % B::BIT_COUNT) == 0
+    }
+
     ///
     /// Returns an iterator over all set bits in this bitmap.
     /// 
-    pub fn iter(&self) -> BitmapSliceIter<B> {
+    pub fn iter(&self) -> BitmapSliceIter<'_, B, O> {
         BitmapSliceIter::new(self.as_slice())
     }
 
+    ///
+    /// Returns an iterator over all set bits in this bitmap in descending order.
+    /// 
+    pub fn iter_rev(&self) -> BitmapSliceRevIter<'_, B, O> {
+        BitmapSliceRevIter::new(self.as_slice())
+    }
+
     ///
     /// Returns an iterator over all ranges of set bits in this bitmap.
     /// 
-    pub fn range_iter(&self) -> BitmapSliceRangeIter<B> {
+    pub fn range_iter(&self) -> BitmapSliceRangeIter<'_, B, O> {
         BitmapSliceRangeIter::new(self.as_slice())
     }
 
+    ///
+    /// Returns an iterator over all clear bits in this bitmap.
+    /// 
+    pub fn clear_iter(&self) -> BitmapClearIter<'_, B, O> {
+        BitmapClearIter::new(self.as_slice())
+    }
+
+    ///
+    /// Returns an iterator over all ranges of clear bits in this bitmap.
+    /// 
+    pub fn clear_range_iter(&self) -> BitmapClearRangeIter<'_, B, O> {
+        BitmapClearRangeIter::new(self.as_slice())
+    }
+
+    ///
+    /// Returns a lazy iterator over consecutive blocks of `block_bits` bits, yielding the
+    /// start of each block and the number of set bits within it. The last block is shorter
+    /// if the size of this bitmap is not a multiple of `block_bits`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `block_bits` is zero.
+    /// 
+    pub fn block_popcounts(&self, block_bits: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        if block_bits == 0 {
+            panic!("Invalid block size ({})", block_bits);
+        }
+
+        let slice = self.as_slice();
+        (0..slice.size())
+            .step_by(block_bits)
+            .map(move |block_start| {
+                let block_end = core::cmp::min(block_start + block_bits, slice.size());
+                (block_start, slice.count_ones_in_range(block_start..block_end))
+            })
+    }
+
+    ///
+    /// Returns an iterator over every backing word in this bitmap, including words
+    /// without any set bits.
+    /// 
+    pub fn word_iter_all(&self) -> BitmapSliceWordIter<'_, B, O> {
+        BitmapSliceWordIter::new(self.as_slice())
+    }
+
+    ///
+    /// This routine returns the index of the highest backing word that contains a set bit.
+    /// If this bitmap does not contain any set bits, None is returned.
+    /// 
+    pub fn highest_active_word(&self) -> Option<usize> {
+        self.as_slice().highest_active_word()
+    }
+
+    ///
+    /// This routine returns the range of backing word indices from the word holding the first
+    /// set bit to the word holding the last set bit (inclusive). If this bitmap does not contain
+    /// any set bits, None is returned.
+    /// 
+    pub fn active_word_range(&self) -> Option<Range<usize>> {
+        self.as_slice().active_word_range()
+    }
+
+    ///
+    /// This routine returns the backing words that lie entirely within this bitmap, excluding
+    /// a partially used last word. The returned tuple also contains the number of leading and
+    /// trailing bits outside of the aligned words; the leading count is always zero for a
+    /// bitmap. If no backing word is fully used, None is returned.
+    /// 
+    pub fn aligned_interior(&self) -> Option<(&[B], usize, usize)> {
+        let full_slots = self.bit_count / B::BIT_COUNT;
+        if full_slots == 0 {
+            None
+
+        } else {
+            Some((&self.bitmap_store.as_ref()[..full_slots], 0, self.bit_count % B::BIT_COUNT))
+        }
+    }
+
     ///
     /// This routine returns a [slice::BitmapSlice](BitmapSlice) starting at the first bit
     /// in the range (inclusive), and ending at the last bit in the range (exclusive).
     /// 
-    pub fn subslice(&self, bit_range: Range<usize>) -> BitmapSlice<B> {
-        BitmapSlice::new(self.bitmap_store.as_ref(), bit_range)
+    pub fn subslice(&self, bit_range: Range<usize>) -> BitmapSlice<'_, B, O> {
+        if bit_range.end > self.bit_count {
+            panic!("Invalid bit range [{}:{}] for bitmap of size {}",
+                   bit_range.start,
+                   bit_range.end,
+                   self.bit_count);
+        }
+
+        BitmapSlice::with_order(self.bitmap_store.as_ref(), bit_range, O::default())
+    }
+
+    ///
+    /// This routine returns a tuple containing the number of bits that must be set and the
+    /// number of bits that must be cleared to transform this bitmap into `target`.
+    /// 
+    pub fn change_counts<OS: AsRef<[B]> + ?Sized>(&self, target: &Bitmap<OS, B, O>) -> (usize, usize) {
+        self.as_slice().change_counts(&target.as_slice())
+    }
+
+    ///
+    /// This routine returns the number of bit positions that differ between this bitmap and
+    /// `other`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if this bitmap and `other` have different sizes.
+    /// 
+    pub fn hamming_distance<OS: AsRef<[B]> + ?Sized>(&self, other: &Bitmap<OS, B, O>) -> usize {
+        self.as_slice().hamming_distance(&other.as_slice())
+    }
+
+    ///
+    /// This routine returns `true` if every bit set in this bitmap is also set in `other`,
+    /// otherwise returns false. The shorter bitmap is treated as if it were extended with
+    /// clear bits to the length of the longer bitmap.
+    /// 
+    pub fn is_subset_of<OS: AsRef<[B]> + ?Sized>(&self, other: &Bitmap<OS, B, O>) -> bool {
+        self.as_slice().is_subset_of(&other.as_slice())
+    }
+
+    ///
+    /// This routine returns `true` if every bit set in `other` is also set in this bitmap,
+    /// otherwise returns false. The shorter bitmap is treated as if it were extended with
+    /// clear bits to the length of the longer bitmap.
+    /// 
+    pub fn is_superset_of<OS: AsRef<[B]> + ?Sized>(&self, other: &Bitmap<OS, B, O>) -> bool {
+        self.as_slice().is_superset_of(&other.as_slice())
+    }
+
+    ///
+    /// This routine returns `true` if this bitmap and `other` do not have any set bits in
+    /// common, otherwise returns false.
+    /// 
+    pub fn is_disjoint<OS: AsRef<[B]> + ?Sized>(&self, other: &Bitmap<OS, B, O>) -> bool {
+        self.as_slice().is_disjoint(&other.as_slice())
+    }
+
+    ///
+    /// This routine returns a tuple containing the zero based index and the length of the
+    /// longest run of bits that are set in both this bitmap and `other`. Ties are broken in
+    /// favor of the lower index. If the bitmaps do not have any set bits in common, None is
+    /// returned.
+    /// 
+    pub fn longest_common_set_run<OS: AsRef<[B]> + ?Sized>(&self, other: &Bitmap<OS, B, O>) -> Option<(usize, usize)> {
+        self.as_slice().longest_common_set_run(&other.as_slice())
+    }
+
+    ///
+    /// Creates a new owned bitmap with one bit per backing word covered by this bitmap. Bit
+    /// `i` is set if backing word `i` contains any set bit within this bitmap. Padding bits in
+    /// the last word are ignored.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn word_occupancy_bitmap(&self) -> Bitmap<Vec<B>, B, O> {
+        let word_count = crate::polyfill::div_ceil(self.bit_count, B::BIT_COUNT);
+        let mut occupancy = Bitmap::with_order_and_bit_count(vec![B::ZERO; crate::polyfill::div_ceil(word_count, B::BIT_COUNT)], word_count, O::default());
+        for (word_index, word) in self.word_iter_all().enumerate() {
+            if word != B::ZERO {
+                occupancy.set_bit(word_index);
+            }
+        }
+
+        occupancy
+    }
+
+    ///
+    /// Creates a new owned bitmap containing a copy of the current contents of this
+    /// bitmap. The backing store is copied in bulk rather than bit-by-bit, and the
+    /// returned bitmap has the same length as this bitmap.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn snapshot(&self) -> Bitmap<Vec<B>, B, O> {
+        let buffer = self.bitmap_store.as_ref();
+        let mut snapshot_store = vec![B::ZERO; buffer.len()];
+        snapshot_store.copy_from_slice(buffer);
+
+        Bitmap::with_order_and_bit_count(snapshot_store, self.bit_count, O::default())
+    }
+
+    ///
+    /// Creates a new owned bitmap of `universe` bits that is the complement of this bitmap
+    /// over `0..universe`. Bits of this bitmap at or past `universe` are ignored, and if this
+    /// bitmap is shorter than `universe`, the missing bits are treated as clear and are set in
+    /// the result.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn complement_within(&self, universe: usize) -> Bitmap<Vec<B>, B, O> {
+        let copied_bit_count = core::cmp::min(self.bit_count, universe);
+        let mut complement = Bitmap::with_order_and_bit_count(vec![B::ZERO; crate::polyfill::div_ceil(universe, B::BIT_COUNT)], universe, O::default());
+        complement.as_slice_mut().copy_bits_from(0, &self.as_slice(), 0..copied_bit_count);
+        complement.invert();
+        complement
+    }
+
+    ///
+    /// Creates a new owned bitmap with bit `i` set wherever `get_bit(i) != get_bit(i - 1)`,
+    /// marking the start and the end of every run of set bits. Bit 0 is compared against an
+    /// implicit clear bit. This is computed one word at a time by XOR-ing with a copy shifted
+    /// one bit towards higher indices.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn boundaries(&self) -> Bitmap<Vec<B>, B, O> {
+        let mut shifted = self.snapshot();
+        shifted.shift_left(1);
+
+        let mut boundaries = self.snapshot();
+        boundaries ^= &shifted;
+        boundaries
+    }
+
+    ///
+    /// Creates a new owned bitmap containing the Gray code of this bitmap, treating this
+    /// bitmap as an unsigned binary number with bit 0 as its least significant bit. Bit `i`
+    /// of the result is `get_bit(i) ^ get_bit(i + 1)`, where the bit past the end is clear.
+    /// This is computed one word at a time by XOR-ing with a copy shifted towards bit 0.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn gray_encode(&self) -> Bitmap<Vec<B>, B, O> {
+        let mut shifted = self.snapshot();
+        shifted.shift_right(1);
+
+        let mut encoded = self.snapshot();
+        encoded ^= &shifted;
+        encoded
+    }
+
+    ///
+    /// Creates a new owned bitmap by decoding this bitmap as a Gray code produced by
+    /// [gray_encode](Bitmap::gray_encode). Bit `i` of the result is the XOR of every bit of
+    /// this bitmap at index `i` or above. This is computed one word at a time with a
+    /// logarithmic number of shifted XOR passes.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn gray_decode(&self) -> Bitmap<Vec<B>, B, O> {
+        let mut decoded = self.snapshot();
+        let mut shift = 1;
+        while shift < self.bit_count {
+            let mut shifted = decoded.snapshot();
+            shifted.shift_right(shift);
+            decoded ^= &shifted;
+            shift *= 2;
+        }
+
+        decoded
+    }
+
+    ///
+    /// Creates a new owned bitmap where bit `i` is equal to bit `perm[i]` of this bitmap.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm.len()` is not equal to the size of this bitmap, or if any entry of
+    /// `perm` is not a valid bit index of this bitmap.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn permute(&self, perm: &[usize]) -> Bitmap<Vec<B>, B, O> {
+        if perm.len() != self.bit_count {
+            panic!("Invalid permutation length ({} != {})", perm.len(), self.bit_count);
+        }
+
+        perm.iter()
+            .map(|source_index| {
+                if *source_index >= self.bit_count {
+                    panic!("Invalid bit index ({} >= {})", source_index, self.bit_count);
+                }
+
+                self.get_bit(*source_index)
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the bits of this bitmap packed into bytes in little endian bit order, where
+    /// bit `i` of this bitmap is bit `i % 8` of byte `i / 8`. Padding bits are cleared, so the
+    /// result does not depend on the backing word type or the byte order of the platform.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.as_slice().to_bytes()
+    }
+
+    ///
+    /// Returns the bits of this bitmap packed into bytes in big endian bit order, where bit 0
+    /// of this bitmap is the most significant bit of the first byte. The final byte is padded
+    /// with clear bits when the length of this bitmap is not a multiple of 8.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; crate::polyfill::div_ceil(self.bit_count, 8)];
+        for bit_index in self.iter() {
+            bytes[bit_index / 8] |= 0x80 >> (bit_index % 8);
+        }
+
+        bytes
+    }
+
+    ///
+    /// Creates a new owned bitmap of the same length as this bitmap containing only the
+    /// first `n` set bits of this bitmap. Every other bit in the returned bitmap is clear.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn take_first_set(&self, n: usize) -> Bitmap<Vec<B>, B, O> {
+        let slot_count = crate::polyfill::div_ceil(self.bit_count, B::BIT_COUNT);
+        let mut prefix = Bitmap::with_order_and_bit_count(vec![B::ZERO; slot_count], self.bit_count, O::default());
+
+        let mut remaining_count = n;
+        for (range_start, range_count) in self.range_iter() {
+            if remaining_count == 0 {
+                break;
+            }
+
+            let taken_count = core::cmp::min(range_count, remaining_count);
+            prefix.set_bit_range(range_start..(range_start + taken_count));
+            remaining_count -= taken_count;
+        }
+
+        prefix
+    }
+
+    ///
+    /// Creates a new owned bitmap with a bit set at every position where `a` and `b` both
+    /// changed relative to `base` but disagree with each other, i.e.
+    /// `(a ^ base) & (b ^ base) & (a ^ b)`. Since each position holds a single bit, two sides
+    /// that both changed a position always agree on its new value, so identical edits are
+    /// never reported. The result has the length of the shortest of the three bitmaps.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn conflict_mask(base: &Self, a: &Self, b: &Self) -> Bitmap<Vec<B>, B, O> {
+        let bit_count = core::cmp::min(base.bit_count, core::cmp::min(a.bit_count, b.bit_count));
+        let conflict_store = base.bitmap_store
+            .as_ref()
+            .iter()
+            .zip(a.bitmap_store.as_ref().iter())
+            .zip(b.bitmap_store.as_ref().iter())
+            .take(crate::polyfill::div_ceil(bit_count, B::BIT_COUNT))
+            .map(|((base, a), b)| (*a ^ *base) & (*b ^ *base) & (*a ^ *b))
+            .collect();
+
+        Bitmap::with_order_and_bit_count(conflict_store, bit_count, O::default())
+    }
+
+    ///
+    /// Encodes the set bits of this bitmap as a sequence of LEB128 varints. Each varint holds
+    /// the number of clear bits between a set bit and the previous set bit, or the start of
+    /// this bitmap for the first set bit. This is much smaller than [to_bytes](Bitmap::to_bytes)
+    /// for sparse bitmaps. The length of this bitmap is not encoded.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn to_delta_varints(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut next_bit = 0;
+        for bit_index in self.iter() {
+            let mut gap = bit_index - next_bit;
+            while gap >= 0x80 {
+                bytes.push((gap as u8) | 0x80);
+                gap >>= 7;
+            }
+
+            bytes.push(gap as u8);
+            next_bit = bit_index + 1;
+        }
+
+        bytes
+    }
+
+    ///
+    /// Creates a new owned bitmap with a bit set at every position where at least two of `a`,
+    /// `b` and `c` have the bit set, computed one word at a time as `(a & b) | (b & c) | (a & c)`.
+    /// The result has the length of the longest of the three bitmaps, with the shorter bitmaps
+    /// treated as if they were extended with clear bits.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn majority(a: &Self, b: &Self, c: &Self) -> Bitmap<Vec<B>, B, O> {
+        let bit_count = core::cmp::max(a.bit_count, core::cmp::max(b.bit_count, c.bit_count));
+        let zeros = core::iter::repeat(B::ZERO);
+        let majority_store = a.word_iter_all().chain(zeros.clone())
+            .zip(b.word_iter_all().chain(zeros.clone()))
+            .zip(c.word_iter_all().chain(zeros))
+            .take(crate::polyfill::div_ceil(bit_count, B::BIT_COUNT))
+            .map(|((a, b), c)| (a & b) | (b & c) | (a & c))
+            .collect();
+
+        Bitmap::with_order_and_bit_count(majority_store, bit_count, O::default())
+    }
+
+    ///
+    /// Performs a three-way merge of `a` and `b` against their common ancestor `base`. If no
+    /// position is in the [conflict mask](Bitmap::conflict_mask), returns `Ok` with a new owned
+    /// bitmap containing `base` with the changes from both sides applied, i.e.
+    /// `base ^ ((a ^ base) | (b ^ base))`, so a position changed identically by both sides is
+    /// changed once. Otherwise returns `Err` with the conflict mask.
+    /// 
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn merge3(base: &Self, a: &Self, b: &Self) -> Result<Bitmap<Vec<B>, B, O>, Bitmap<Vec<B>, B, O>> {
+        let conflicts = Self::conflict_mask(base, a, b);
+        if conflicts.find_first_set().is_some() {
+            return Err(conflicts);
+        }
+
+        let bit_count = conflicts.bit_count;
+        let merged_store = base.bitmap_store
+            .as_ref()
+            .iter()
+            .zip(a.bitmap_store.as_ref().iter())
+            .zip(b.bitmap_store.as_ref().iter())
+            .take(crate::polyfill::div_ceil(bit_count, B::BIT_COUNT))
+            .map(|((base, a), b)| *base ^ ((*a ^ *base) | (*b ^ *base)))
+            .collect();
+
+        Ok(Bitmap::with_order_and_bit_count(merged_store, bit_count, O::default()))
     }
 
     ///
@@ -103,28 +673,94 @@ impl<S: AsRef<[B]> + ?Sized, B: BitStore> Bitmap<S, B> {
 
 }
 
-impl<S: AsRef<[B]> + ?Sized, B: BitStore> BitmapOpts for Bitmap<S, B> {
+impl<S: AsRef<[B]> + ?Sized, B: BitStore, O: BitOrder> BitmapOpts for Bitmap<S, B, O> {
     
+    fn count_ones(&self) -> usize {
+        self.as_slice().count_ones()
+    }
+
+    fn count_ones_in_range(&self, range: Range<usize>) -> usize {
+        self.as_slice().count_ones_in_range(range)
+    }
+
     fn find_next_clear_in_range(&self, range: Range<usize>) -> Option<usize> {
         self.as_slice().find_next_clear_in_range(range)
     }
 
+    fn trailing_zeros(&self) -> usize {
+        self.as_slice().trailing_zeros()
+    }
+
+    fn leading_zeros(&self) -> usize {
+        self.as_slice().leading_zeros()
+    }
+
+    fn for_each_clear_range<F: FnMut(usize, usize)>(&self, f: F)
+        where Self: Sized {
+        self.as_slice().for_each_clear_range(f)
+    }
+
+    fn for_each_set_range<F: FnMut(usize, usize)>(&self, f: F)
+        where Self: Sized {
+
+        self.as_slice().for_each_set_range(f)
+    }
+
+    fn has_isolated_set_bits(&self) -> bool {
+        self.as_slice().has_isolated_set_bits()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    fn is_full(&self) -> bool {
+        self.as_slice().is_full()
+    }
+
+    fn parity(&self) -> bool {
+        self.as_slice().parity()
+    }
+
     fn find_next_set_in_range(&self, range: Range<usize>) -> Option<usize> {
         self.as_slice().find_next_set_in_range(range)
     }
 
+    fn find_prev_clear_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.as_slice().find_prev_clear_in_range(range)
+    }
+
+    fn find_prev_set_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.as_slice().find_prev_set_in_range(range)
+    }
+
+    fn select(&self, n: usize) -> Option<usize> {
+        self.as_slice().select(n)
+    }
+
     fn get_bit(&self, bit_index: usize) -> bool {
         self.as_slice().get_bit(bit_index)
     }
 
     fn size(&self) -> usize {
-        self.as_slice().size()
+        self.bit_count
     }
 
 }
 
-impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore> Bitmap<S, B> {
+impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore, O: BitOrder> Bitmap<S, B, O> {
     
+    ///
+    /// Overwrites the contents of this bitmap with the contents of `target`, only writing
+    /// backing words that differ from the corresponding word in `target`. Words that
+    /// already match are never written, which avoids dirtying unchanged pages. Only the
+    /// overlapping bits of the two bitmaps are compared. Returns the number of words
+    /// that were written.
+    /// 
+    pub fn assign_minimal<OS: AsRef<[B]> + ?Sized>(&mut self, target: &Bitmap<OS, B, O>) -> usize {
+        self.combine_words(target, |_, src| src)
+    }
+
     ///
     /// Converts this bitmap into a [BitmapSliceMut](crate::slice::BitmapSliceMut) over the backing
     /// storage. The returned slice has the same length as this bitmap instance.
@@ -133,14 +769,12 @@ impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore> Bitmap<S, B> {
     /// 
     /// Panics if the backing storage is larger than [MAXIMUM_BUFFER_SIZE](crate::bitmap::Bitmap::MAXIMUM_BUFFER_SIZE)
     /// 
-    pub fn as_slice_mut(&mut self) -> BitmapSliceMut<B> {
+    pub fn as_slice_mut(&mut self) -> BitmapSliceMut<'_, B, O> {
         let buffer = self.bitmap_store.as_mut();
-        if buffer.len() > Self::MAXIMUM_BUFFER_SIZE {
-            panic!("Bitmap buffer is too large ({} > {})", buffer.len(), Self::MAXIMUM_BUFFER_SIZE);
-        }
+        Self::validate_buffer(buffer.len(), self.bit_count);
 
         unsafe {
-            BitmapSliceMut::new_unchecked(buffer, 0, buffer.len() * B::BIT_COUNT)
+            BitmapSliceMut::from_raw_parts(NonNull::new_unchecked(buffer.as_mut_ptr()), 0, self.bit_count)
         }
     }
 
@@ -149,13 +783,130 @@ impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore> Bitmap<S, B> {
     /// first bit in the range (inclusive), and ending at the last bit in the range
     /// (exclusive).
     /// 
-    pub fn subslice_mut(&mut self, bit_range: Range<usize>) -> BitmapSliceMut<B> {
-        BitmapSliceMut::new(self.bitmap_store.as_mut(), bit_range)
+    pub fn subslice_mut(&mut self, bit_range: Range<usize>) -> BitmapSliceMut<'_, B, O> {
+        if bit_range.end > self.bit_count {
+            panic!("Invalid bit range [{}:{}] for bitmap of size {}",
+                   bit_range.start,
+                   bit_range.end,
+                   self.bit_count);
+        }
+
+        BitmapSliceMut::with_order(self.bitmap_store.as_mut(), bit_range, O::default())
+    }
+
+    ///
+    /// This routine performs `self &= rhs`, requiring both bitmaps to have the same logical
+    /// length. If the lengths differ, this bitmap is left unchanged and a
+    /// [BitmapError::LengthMismatch](crate::error::BitmapError::LengthMismatch) is returned.
+    /// 
+    pub fn checked_and_assign<OS: AsRef<[B]> + ?Sized>(&mut self, rhs: &Bitmap<OS, B, O>) -> Result<(), BitmapError> {
+        self.check_same_length(rhs)?;
+        self.combine_words(rhs, |dest, src| dest & src);
+        Ok(())
+    }
+
+    ///
+    /// This routine performs `self |= rhs`, requiring both bitmaps to have the same logical
+    /// length. If the lengths differ, this bitmap is left unchanged and a
+    /// [BitmapError::LengthMismatch](crate::error::BitmapError::LengthMismatch) is returned.
+    /// 
+    pub fn checked_or_assign<OS: AsRef<[B]> + ?Sized>(&mut self, rhs: &Bitmap<OS, B, O>) -> Result<(), BitmapError> {
+        self.check_same_length(rhs)?;
+        self.combine_words(rhs, |dest, src| dest | src);
+        Ok(())
+    }
+
+    ///
+    /// This routine performs `self ^= rhs`, requiring both bitmaps to have the same logical
+    /// length. If the lengths differ, this bitmap is left unchanged and a
+    /// [BitmapError::LengthMismatch](crate::error::BitmapError::LengthMismatch) is returned.
+    /// 
+    pub fn checked_xor_assign<OS: AsRef<[B]> + ?Sized>(&mut self, rhs: &Bitmap<OS, B, O>) -> Result<(), BitmapError> {
+        self.check_same_length(rhs)?;
+        self.combine_words(rhs, |dest, src| dest ^ src);
+        Ok(())
+    }
+
+    fn check_same_length<OS: AsRef<[B]> + ?Sized>(&self, rhs: &Bitmap<OS, B, O>) -> Result<(), BitmapError> {
+        if self.bit_count != rhs.bit_count {
+            return Err(BitmapError::LengthMismatch { lhs_bit_count: self.bit_count, rhs_bit_count: rhs.bit_count });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// This routine reverses the order of the bits in this bitmap, so bit `i` swaps with bit
+    /// `size() - 1 - i`. Padding bits past the end of this bitmap are left unchanged.
+    /// 
+    pub fn reverse(&mut self) {
+        self.as_slice_mut().reverse()
+    }
+
+    ///
+    /// This routine rotates every bit in this bitmap `n` positions towards higher bit indices,
+    /// with bits rotated past the end reappearing at the start.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn rotate_left(&mut self, n: usize) {
+        self.as_slice_mut().rotate_left(n)
+    }
+
+    ///
+    /// This routine rotates every bit in this bitmap `n` positions towards lower bit indices,
+    /// with bits rotated past the start reappearing at the end.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn rotate_right(&mut self, n: usize) {
+        self.as_slice_mut().rotate_right(n)
+    }
+
+    ///
+    /// This routine shifts every bit in this bitmap `n` positions towards higher bit indices,
+    /// discarding bits shifted past the end and clearing the vacated low bits.
+    /// 
+    pub fn shift_left(&mut self, n: usize) {
+        self.as_slice_mut().shift_left(n)
+    }
+
+    ///
+    /// This routine shifts every bit in this bitmap `n` positions towards lower bit indices,
+    /// discarding bits shifted past the start and clearing the vacated high bits.
+    /// 
+    pub fn shift_right(&mut self, n: usize) {
+        self.as_slice_mut().shift_right(n)
+    }
+
+    pub(super) fn combine_words<OS: AsRef<[B]> + ?Sized>(&mut self, rhs: &Bitmap<OS, B, O>, combine: impl Fn(B, B) -> B) -> usize {
+        let overlapping_bits = core::cmp::min(self.bit_count, rhs.bit_count);
+        let full_words = overlapping_bits / B::BIT_COUNT;
+        let trailing_mask = O::create_range_mask::<B>(0, overlapping_bits % B::BIT_COUNT);
+
+        let mut written_words = 0;
+        self.bitmap_store
+            .as_mut()
+            .iter_mut()
+            .zip(rhs.bitmap_store.as_ref().iter())
+            .take(crate::polyfill::div_ceil(overlapping_bits, B::BIT_COUNT))
+            .enumerate()
+            .for_each(|(word_index, (dest, src))| {
+                let mut combined = combine(*dest, *src);
+                if word_index == full_words {
+                    combined = (combined & trailing_mask) | (*dest & !trailing_mask);
+                }
+
+                if combined != *dest {
+                    *dest = combined;
+                    written_words += 1;
+                }
+            });
+
+        written_words
     }
 
 }
 
-impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore> BitmapOptsMut for Bitmap<S, B> {
+impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore, O: BitOrder> BitmapOptsMut for Bitmap<S, B, O> {
 
     ///
     /// This routine clears the bit at the provided index.
@@ -171,6 +922,37 @@ impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore> BitmapOptsMut for Bitmap<
         self.as_slice_mut().clear_bit_range(bit_range)
     }
 
+    ///
+    /// This routine clears every bit in this bitmap. Padding bits past the logical length in
+    /// the final backing slot are left unchanged.
+    /// 
+    fn clear_all(&mut self) {
+        self.as_slice_mut().clear_all()
+    }
+
+    ///
+    /// This routine clears every bit outside of `range` in a single masked pass over the
+    /// backing slots.
+    /// 
+    fn keep_range(&mut self, range: Range<usize>) {
+        self.as_slice_mut().keep_range(range)
+    }
+
+    ///
+    /// This routine toggles every bit in this bitmap.
+    /// 
+    fn invert(&mut self) {
+        self.as_slice_mut().invert()
+    }
+
+    ///
+    /// This routine sets every bit in this bitmap. Padding bits past the logical length in
+    /// the final backing slot are left unchanged.
+    /// 
+    fn set_all(&mut self) {
+        self.as_slice_mut().set_all()
+    }
+
     ///
     /// This routine sets the bit at the provided index.
     /// 
@@ -185,6 +967,13 @@ impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore> BitmapOptsMut for Bitmap<
         self.as_slice_mut().set_bit_range(bit_range)
     }
 
+    ///
+    /// This routine exchanges the values of the bits at indices `i` and `j`.
+    /// 
+    fn swap_bits(&mut self, i: usize, j: usize) {
+        self.as_slice_mut().swap_bits(i, j)
+    }
+
     ///
     /// This routine toggles the bit at the provided index.
     /// 
@@ -199,4 +988,249 @@ impl<S: AsRef<[B]> + AsMut<[B]> + ?Sized, B: BitStore> BitmapOptsMut for Bitmap<
         self.as_slice_mut().toggle_bit_range(bit_range)
     }
 
+    ///
+    /// This routine toggles the bit at every index in `indices`. Duplicate indices cancel out.
+    /// 
+    fn toggle_bits(&mut self, indices: &[usize]) {
+        self.as_slice_mut().toggle_bits(indices)
+    }
+
+}
+
+#[cfg(feature = "alloc")]
+impl<B: BitStore, O: BitOrder> Bitmap<Vec<B>, B, O> {
+
+    ///
+    /// Creates a new bitmap of `bit_len` bits from bytes in little endian bit order, where
+    /// bit `i % 8` of byte `i / 8` is bit `i` of the bitmap. This is the inverse of
+    /// [to_bytes](Bitmap::to_bytes).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `bytes` does not contain at least `bit_len` bits.
+    /// 
+    pub fn from_bytes(bytes: &[u8], bit_len: usize) -> Self {
+        if bit_len > (bytes.len() * 8) {
+            panic!("Invalid bit count {} for buffer of {} bits", bit_len, bytes.len() * 8);
+        }
+
+        (0..bit_len)
+            .map(|bit_index| (bytes[bit_index / 8] & (1 << (bit_index % 8))) != 0)
+            .collect()
+    }
+
+    ///
+    /// Creates a new bitmap of `bit_count` bits from bytes in big endian bit order, where the
+    /// most significant bit of the first byte is bit 0 of the bitmap. This is the inverse of
+    /// [to_be_bytes](Bitmap::to_be_bytes).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `bytes` does not contain at least `bit_count` bits.
+    /// 
+    pub fn from_be_bytes(bytes: &[u8], bit_count: usize) -> Self {
+        if bit_count > (bytes.len() * 8) {
+            panic!("Invalid bit count {} for buffer of {} bits", bit_count, bytes.len() * 8);
+        }
+
+        (0..bit_count)
+            .map(|bit_index| (bytes[bit_index / 8] & (0x80 >> (bit_index % 8))) != 0)
+            .collect()
+    }
+
+    ///
+    /// Creates a new bitmap of `len_bits` bits with only the bits at `indices` set. Unlike
+    /// collecting indices into a bitmap, the length is explicit and may extend past the
+    /// largest index.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if any index is not within `len_bits` bits.
+    /// 
+    pub fn from_set_indices(len_bits: usize, indices: &[usize]) -> Self {
+        let mut bitmap = Bitmap::with_order_and_bit_count(vec![B::ZERO; crate::polyfill::div_ceil(len_bits, B::BIT_COUNT)], len_bits, O::default());
+        for bit_index in indices {
+            if *bit_index >= len_bits {
+                panic!("Invalid bit index ({} >= {})", bit_index, len_bits);
+            }
+
+            bitmap.set_bit(*bit_index);
+        }
+
+        bitmap
+    }
+
+    ///
+    /// Creates a new bitmap of `len_bits` bits with every bit set except the bits at `indices`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if any index is not within `len_bits` bits.
+    /// 
+    pub fn clear_indices(len_bits: usize, indices: &[usize]) -> Self {
+        let mut bitmap = Bitmap::with_order_and_bit_count(vec![B::MAX; crate::polyfill::div_ceil(len_bits, B::BIT_COUNT)], len_bits, O::default());
+        for bit_index in indices {
+            if *bit_index >= len_bits {
+                panic!("Invalid bit index ({} >= {})", bit_index, len_bits);
+            }
+
+            bitmap.clear_bit(*bit_index);
+        }
+
+        bitmap
+    }
+
+    ///
+    /// Creates a new bitmap of `bit_len` bits from the LEB128 varint encoding produced by
+    /// [to_delta_varints](Bitmap::to_delta_varints). A [DecodeError](crate::error::DecodeError)
+    /// is returned if `bytes` ends in the middle of a varint, if a varint does not fit in a
+    /// usize, or if a decoded bit index is not within `bit_len` bits.
+    /// 
+    pub fn from_delta_varints(bytes: &[u8], bit_len: usize) -> Result<Self, DecodeError> {
+        let mut bitmap = Bitmap::with_order_and_bit_count(vec![B::ZERO; crate::polyfill::div_ceil(bit_len, B::BIT_COUNT)], bit_len, O::default());
+        let mut next_bit = 0usize;
+        let mut gap = 0usize;
+        let mut shift = 0;
+        for (position, byte) in bytes.iter().enumerate() {
+            let group = (byte & 0x7F) as usize;
+            match group.checked_shl(shift).filter(|shifted_group| (shifted_group >> shift) == group) {
+                Some(shifted_group) => gap |= shifted_group,
+                None => return Err(DecodeError::VarintOverflow { position })
+            }
+
+            if (byte & 0x80) != 0 {
+                shift += 7;
+                continue;
+            }
+
+            let bit_index = next_bit.saturating_add(gap);
+            if bit_index >= bit_len {
+                return Err(DecodeError::BitIndexOutOfRange { bit_index, bit_count: bit_len, position });
+            }
+
+            bitmap.set_bit(bit_index);
+            next_bit = bit_index + 1;
+            gap = 0;
+            shift = 0;
+        }
+
+        if shift != 0 {
+            return Err(DecodeError::TruncatedVarint { byte_count: bytes.len() });
+        }
+
+        Ok(bitmap)
+    }
+
+    ///
+    /// Parses a bitmap from a string of `'0'` and `'1'` characters, where the first digit
+    /// is bit 0. Whitespace and `'_'` separators are ignored, and the length of the resulting
+    /// bitmap is the number of digits in the string. Any other character results in a
+    /// [ParseError](crate::error::ParseError).
+    /// 
+    pub fn from_bit_string(bit_string: &str) -> Result<Self, ParseError> {
+        bit_string
+            .char_indices()
+            .filter(|(_, character)| (*character != '_') && !character.is_whitespace())
+            .map(|(position, character)| {
+                match character {
+                    '0' => Ok(false),
+                    '1' => Ok(true),
+                    character => Err(ParseError::InvalidCharacter { position, character })
+                }
+            })
+            .collect()
+    }
+
+    ///
+    /// Creates a new empty bitmap with enough backing storage preallocated to hold
+    /// `bit_count` bits without reallocating.
+    /// 
+    pub fn with_capacity_bits(bit_count: usize) -> Self {
+        let slot_count = crate::polyfill::div_ceil(bit_count, B::BIT_COUNT);
+        Bitmap::with_order_and_bit_count(Vec::with_capacity(slot_count), 0, O::default())
+    }
+
+    ///
+    /// Sets every bit in this bitmap that is set in `other`, first growing this bitmap with
+    /// clear bits to the length of `other` if `other` is longer. Unlike `|=`, which only
+    /// combines the overlapping prefix, the resulting length is the larger of the two lengths.
+    /// 
+    pub fn union_extend<OS: AsRef<[B]> + ?Sized>(&mut self, other: &Bitmap<OS, B, O>) {
+        if other.bit_count > self.bit_count {
+            self.resize_bits(other.bit_count, false);
+        }
+
+        self.combine_words(other, |dest, src| dest | src);
+    }
+
+    ///
+    /// Grows this bitmap with clear bits so that `bit_index` is within it. The bitmap grows
+    /// to the end of the slot holding `bit_index`, so its new size is always a whole number
+    /// of slots. If `bit_index` is already within this bitmap, it is left unchanged.
+    /// 
+    pub fn grow_to_fit(&mut self, bit_index: usize) {
+        if bit_index < self.bit_count {
+            return;
+        }
+
+        self.resize_bits((bit_index / B::BIT_COUNT + 1) * B::BIT_COUNT, false);
+    }
+
+    ///
+    /// Sets the bit at `bit_index`, first growing this bitmap with clear bits as described in
+    /// [grow_to_fit](Self::grow_to_fit) if `bit_index` is not within it.
+    /// 
+    pub fn set_bit_growing(&mut self, bit_index: usize) {
+        self.grow_to_fit(bit_index);
+        self.set_bit(bit_index);
+    }
+
+    ///
+    /// Shrinks this bitmap by dropping every trailing slot that contains no set bits, and
+    /// releases the unused capacity of the backing storage. The slot holding the highest set
+    /// bit is kept, and the size of this bitmap shrinks to the end of that slot. If no bits
+    /// are set, this bitmap becomes empty.
+    /// 
+    pub fn shrink_to_fit(&mut self) {
+        let new_bit_count = match self.find_last_set() {
+            Some(last_set_bit) => core::cmp::min(self.bit_count, (last_set_bit / B::BIT_COUNT + 1) * B::BIT_COUNT),
+            None => 0
+        };
+
+        self.resize_bits(new_bit_count, false);
+        self.bitmap_store.shrink_to_fit();
+    }
+
+    ///
+    /// Resizes this bitmap to contain `new_bit_count` bits, growing or shrinking the backing
+    /// storage as needed. When growing, every new bit is set if `fill` is true and cleared
+    /// otherwise, including any new bits that land in the existing final slot. When shrinking,
+    /// the bits remaining in the final retained slot are left intact.
+    /// 
+    pub fn resize_bits(&mut self, new_bit_count: usize, fill: bool) {
+        let slot_count = crate::polyfill::div_ceil(new_bit_count, B::BIT_COUNT);
+        if new_bit_count > self.bit_count {
+            let old_bit_count = self.bit_count;
+            let old_bit_capacity = self.bit_capacity();
+            let fill_slot = if fill { B::MAX } else { B::ZERO };
+            if slot_count > self.bitmap_store.len() {
+                self.bitmap_store.resize(slot_count, fill_slot);
+            }
+
+            self.bit_count = new_bit_count;
+
+            let existing_range = old_bit_count..core::cmp::min(new_bit_count, old_bit_capacity);
+            if fill {
+                self.set_bit_range(existing_range);
+
+            } else {
+                self.clear_bit_range(existing_range);
+            }
+
+        } else {
+            self.bit_count = new_bit_count;
+            self.bitmap_store.truncate(slot_count);
+        }
+    }
+
 }
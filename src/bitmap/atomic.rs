@@ -0,0 +1,120 @@
+
+use crate::store::{AtomicBitStore, BitStore};
+
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+///
+/// Implements a fixed size bitmap over a slice of atomic values. Every operation takes
+/// `&self` and is performed as an atomic read-modify-write on the backing word, allowing
+/// multiple threads to set and clear bits in a shared bitmap without external locking.
+/// 
+pub struct AtomicBitmap<S: ?Sized, A = core::sync::atomic::AtomicUsize> {
+    _as: PhantomData<A>,
+    bit_count: usize,
+    bitmap_store: S
+}
+
+impl<S, A> AtomicBitmap<S, A> {
+
+    ///
+    /// Consumes this bitmap instance and returns the underlying storage.
+    /// 
+    pub fn into_inner(self) -> S {
+        self.bitmap_store
+    }
+
+}
+
+impl<S: AsRef<[A]>, A: AtomicBitStore> AtomicBitmap<S, A> {
+
+    ///
+    /// Creates a new atomic bitmap with the provided backing store. The bitmap covers every
+    /// bit in the backing store.
+    /// 
+    pub fn new(bitmap_store: S) -> Self {
+        let bit_count = bitmap_store.as_ref().len() * A::Value::BIT_COUNT;
+        AtomicBitmap { _as: PhantomData, bit_count, bitmap_store }
+    }
+
+    ///
+    /// Creates a new atomic bitmap with the provided backing store covering only the first
+    /// `bit_count` bits of the backing store.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the backing storage does not contain at least `bit_count` bits.
+    /// 
+    pub fn with_bit_count(bitmap_store: S, bit_count: usize) -> Self {
+        let buffer_bit_count = bitmap_store.as_ref().len() * A::Value::BIT_COUNT;
+        if bit_count > buffer_bit_count {
+            panic!("Invalid bit count {} for buffer of {} bits", bit_count, buffer_bit_count);
+        }
+
+        AtomicBitmap { _as: PhantomData, bit_count, bitmap_store }
+    }
+
+}
+
+impl<S: AsRef<[A]> + ?Sized, A: AtomicBitStore> AtomicBitmap<S, A> {
+
+    ///
+    /// This routine atomically clears the bit at the provided index, returning `true`
+    /// if the bit was previously set.
+    /// 
+    pub fn clear_bit(&self, bit_index: usize, ordering: Ordering) -> bool {
+        let (slot, offset) = self.translate_bit_index(bit_index);
+        self.bitmap_store.as_ref()[slot].fetch_clear_bit(offset, ordering)
+    }
+
+    ///
+    /// This routine returns `true` if the bit at the provided index is set, otherwise returns false.
+    /// 
+    pub fn get_bit(&self, bit_index: usize, ordering: Ordering) -> bool {
+        let (slot, offset) = self.translate_bit_index(bit_index);
+        let bit_mask = A::Value::create_bit_mask(offset);
+        (self.bitmap_store.as_ref()[slot].load(ordering) & bit_mask) != A::Value::ZERO
+    }
+
+    ///
+    /// This routine atomically sets the bit at the provided index, returning `true`
+    /// if the bit was previously set.
+    /// 
+    pub fn set_bit(&self, bit_index: usize, ordering: Ordering) -> bool {
+        let (slot, offset) = self.translate_bit_index(bit_index);
+        self.bitmap_store.as_ref()[slot].fetch_set_bit(offset, ordering)
+    }
+
+    ///
+    /// This routine returns the total size in bits of this bitmap.
+    /// 
+    pub fn size(&self) -> usize {
+        self.bit_count
+    }
+
+    ///
+    /// Returns a reference to the underlying storage of this bitmap.
+    /// 
+    pub fn store(&self) -> &S {
+        &self.bitmap_store
+    }
+
+    ///
+    /// This routine attempts to set the bit at the provided index, returning `true` if
+    /// this call changed the bit from clear to set. If the bit was already set, or another
+    /// thread set it first, false is returned. This is useful for claiming a bit exactly once.
+    /// 
+    pub fn try_set_bit(&self, bit_index: usize, ordering: Ordering) -> bool {
+        let (slot, offset) = self.translate_bit_index(bit_index);
+        self.bitmap_store.as_ref()[slot].try_set_bit(offset, ordering)
+    }
+
+    fn translate_bit_index(&self, bit_index: usize) -> (usize, usize) {
+        if bit_index >= self.bit_count {
+            panic!("Invalid bit index ({} >= {})", bit_index, self.bit_count);
+        }
+
+        (bit_index / A::Value::BIT_COUNT, bit_index % A::Value::BIT_COUNT)
+    }
+
+}
@@ -0,0 +1,160 @@
+
+use super::Bitmap;
+
+use crate::store::BitStore;
+use crate::traits::{BitmapOpts, BitmapOptsMut};
+
+use core::ops::Range;
+
+use alloc::{vec, vec::Vec};
+
+///
+/// A single journaled range operation, holding the contents of the affected bits before
+/// the operation was applied.
+/// 
+struct JournalEntry<B: BitStore> {
+    bit_start: usize,
+    prior_bits: Bitmap<Vec<B>, B>
+}
+
+///
+/// Implements a wrapper around a bitmap that records every range operation into a journal.
+/// The journal stores the prior contents of the bits touched by each operation, so any
+/// suffix of the journal can be undone with [rollback](JournaledBitmap::rollback) even if
+/// some of the bits were already in the target state.
+/// 
+pub struct JournaledBitmap<S, B: BitStore = usize> {
+    bitmap: Bitmap<S, B>,
+    journal: Vec<JournalEntry<B>>
+}
+
+impl<S: AsRef<[B]>, B: BitStore> JournaledBitmap<S, B> {
+
+    ///
+    /// Creates a new journaled bitmap over the provided bitmap with an empty journal.
+    /// 
+    pub fn new(bitmap: Bitmap<S, B>) -> Self {
+        JournaledBitmap { bitmap, journal: Vec::new() }
+    }
+
+    ///
+    /// Returns the wrapped bitmap.
+    /// 
+    pub fn as_bitmap(&self) -> &Bitmap<S, B> {
+        &self.bitmap
+    }
+
+    ///
+    /// Returns a checkpoint for the current state of this bitmap. Passing the checkpoint to
+    /// [rollback](JournaledBitmap::rollback) undoes every operation recorded after it.
+    /// 
+    pub fn checkpoint(&self) -> usize {
+        self.journal.len()
+    }
+
+    ///
+    /// Discards the journal without modifying the bitmap. Previously returned checkpoints
+    /// are no longer valid.
+    /// 
+    pub fn commit(&mut self) {
+        self.journal.clear();
+    }
+
+    ///
+    /// Consumes this instance and returns the wrapped bitmap, discarding the journal.
+    /// 
+    pub fn into_inner(self) -> Bitmap<S, B> {
+        self.bitmap
+    }
+
+    ///
+    /// Returns the number of operations recorded in the journal.
+    /// 
+    pub fn journal_len(&self) -> usize {
+        self.journal.len()
+    }
+
+    fn record(&mut self, bit_range: Range<usize>) {
+        let source = self.bitmap.subslice(bit_range.clone());
+        let bit_count = source.size();
+
+        let mut prior_bits = Bitmap::with_bit_count(vec![B::ZERO; crate::polyfill::div_ceil(bit_count, B::BIT_COUNT)], bit_count);
+        prior_bits.as_slice_mut().copy_bits_from(0, &source, 0..bit_count);
+
+        self.journal.push(JournalEntry { bit_start: bit_range.start, prior_bits });
+    }
+
+}
+
+impl<S: AsRef<[B]> + AsMut<[B]>, B: BitStore> JournaledBitmap<S, B> {
+
+    ///
+    /// Clears all bits in the provided range and records the operation in the journal.
+    /// 
+    pub fn clear_bit_range(&mut self, bit_range: Range<usize>) {
+        self.record(bit_range.clone());
+        self.bitmap.clear_bit_range(bit_range);
+    }
+
+    ///
+    /// Sets all bits in the provided range and records the operation in the journal.
+    /// 
+    pub fn set_bit_range(&mut self, bit_range: Range<usize>) {
+        self.record(bit_range.clone());
+        self.bitmap.set_bit_range(bit_range);
+    }
+
+    ///
+    /// Toggles all bits in the provided range and records the operation in the journal.
+    /// 
+    pub fn toggle_bit_range(&mut self, bit_range: Range<usize>) {
+        self.record(bit_range.clone());
+        self.bitmap.toggle_bit_range(bit_range);
+    }
+
+    ///
+    /// Undoes every operation recorded after the checkpoint `to`, newest first, restoring
+    /// the prior contents of the affected bits.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `to` is larger than the current journal length.
+    /// 
+    pub fn rollback(&mut self, to: usize) {
+        if to > self.journal.len() {
+            panic!("Invalid journal checkpoint ({} > {})", to, self.journal.len());
+        }
+
+        for entry in self.journal.drain(to..).rev() {
+            let prior_bits = entry.prior_bits.as_slice();
+            self.bitmap
+                .as_slice_mut()
+                .copy_bits_from(entry.bit_start, &prior_bits, 0..prior_bits.size());
+        }
+    }
+
+}
+
+impl<S: AsRef<[B]>, B: BitStore> BitmapOpts for JournaledBitmap<S, B> {
+
+    fn count_ones_in_range(&self, range: Range<usize>) -> usize {
+        self.bitmap.count_ones_in_range(range)
+    }
+
+    fn find_next_clear_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.bitmap.find_next_clear_in_range(range)
+    }
+
+    fn find_next_set_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.bitmap.find_next_set_in_range(range)
+    }
+
+    fn get_bit(&self, bit_index: usize) -> bool {
+        self.bitmap.get_bit(bit_index)
+    }
+
+    fn size(&self) -> usize {
+        self.bitmap.size()
+    }
+
+}
@@ -0,0 +1,59 @@
+
+use super::Bitmap;
+
+use crate::store::{BitOrder, BitStore};
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use alloc::{format, vec, vec::Vec};
+
+///
+/// The serialized form of a bitmap. The backing words covering the logical length of
+/// the bitmap are stored as little endian bytes, with any padding bits cleared, so that
+/// the format does not depend on the byte order of the serializing platform.
+/// 
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "Bitmap")]
+struct SerializedBitmap {
+    bit_count: u64,
+    bytes: Vec<u8>
+}
+
+impl<S: AsRef<[B]> + ?Sized, B: BitStore, O: BitOrder> Serialize for Bitmap<S, B, O> {
+
+    fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        let word_count = crate::polyfill::div_ceil(self.bit_count, B::BIT_COUNT);
+        let mut bytes = vec![0; word_count * B::BYTE_COUNT];
+        self.word_iter_all()
+            .zip(bytes.chunks_exact_mut(B::BYTE_COUNT))
+            .for_each(|(word, word_bytes)| word.write_le_bytes(word_bytes));
+
+        SerializedBitmap { bit_count: self.bit_count as u64, bytes }.serialize(serializer)
+    }
+
+}
+
+impl<'de, S: AsRef<[B]> + FromIterator<B>, B: BitStore, O: BitOrder> Deserialize<'de> for Bitmap<S, B, O> {
+
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedBitmap::deserialize(deserializer)?;
+        let bit_count = usize::try_from(serialized.bit_count)
+            .map_err(|_| D::Error::custom(format!("Bitmap bit count {} is too large", serialized.bit_count)))?;
+
+        let word_count = crate::polyfill::div_ceil(bit_count, B::BIT_COUNT);
+        if serialized.bytes.len() != (word_count * B::BYTE_COUNT) {
+            return Err(D::Error::custom(format!("Invalid byte count {} for bitmap of {} bits",
+                                                serialized.bytes.len(),
+                                                bit_count)));
+        }
+
+        let bitmap_store = serialized.bytes
+            .chunks_exact(B::BYTE_COUNT)
+            .map(B::read_le_bytes)
+            .collect();
+
+        Ok(Bitmap::with_order_and_bit_count(bitmap_store, bit_count, O::default()))
+    }
+
+}
@@ -1,6 +1,7 @@
 
 use super::*;
-use crate::traits::{BitmapOpts, BitmapOptsMut};
+use crate::error::{BitmapError, DecodeError, OutOfBoundsError, ParseError};
+use crate::traits::{BitmapOpts, BitmapOptsAlloc, BitmapOptsMut, BitmapOptsMutAlloc};
 
 #[test]
 fn test_and_assign() {
@@ -105,6 +106,7 @@ fn test_find_next_set_range() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_get_bit() {
     let buffer = [0b10101010u8, 0b11111111, 0b10000000];
     let bitmap = Bitmap::new(&buffer);
@@ -170,3 +172,1277 @@ fn test_toggle_bit() {
     bitmap.toggle_bit_range(0..bitmap.size());
     assert_eq!(*bitmap.store(), &[0b10100101, 0b00001111, 0b11000011]);
 }
+
+#[test]
+fn test_find_clear_run_near() {
+    let buffer = [0b00000000u8, 0b11111111, 0b11111111, 0b00111111, 0b11111000];
+    let bitmap = Bitmap::new(&buffer);
+
+    assert_eq!(bitmap.find_clear_run_near(20, 3), Some(30));
+    assert_eq!(bitmap.find_clear_run_near(12, 3), Some(5));
+    assert_eq!(bitmap.find_clear_run_near(2, 3), Some(2));
+    assert_eq!(bitmap.find_clear_run_near(20, 9), None);
+    assert_eq!(bitmap.find_clear_run_near(18, 3), Some(30));
+    assert_eq!(bitmap.find_clear_run_near(33, 3), Some(32));
+    assert_eq!(bitmap.find_clear_run_near(100, 2), Some(33));
+
+    for len in 1..10 {
+        for target in 0..45 {
+            let expected = (0..=(bitmap.size() - len))
+                .filter(|start| (*start..(*start + len)).all(|bit_index| !bitmap.get_bit(bit_index)))
+                .min_by_key(|start| (start.abs_diff(target), *start));
+            assert_eq!(bitmap.find_clear_run_near(target, len), expected);
+        }
+    }
+}
+
+#[test]
+fn test_word_iter_all() {
+    let buffer = [0x00000000FFFF0000u64, 0x0000000000000000, 0x8000000000000001];
+    let bitmap = Bitmap::new(&buffer);
+
+    let words: Vec<u64> = bitmap.word_iter_all().collect();
+    assert_eq!(words, buffer);
+}
+
+#[test]
+fn test_snapshot() {
+    let mut bitmap = Bitmap::new(vec![0u16; 2]);
+    bitmap.set_bit_range(4..20);
+
+    let snapshot = bitmap.snapshot();
+    bitmap.clear_bit_range(0..bitmap.size());
+    bitmap.set_bit(31);
+
+    assert_eq!(snapshot.size(), bitmap.size());
+    assert_eq!(*snapshot.store(), [0xFFF0, 0x000F]);
+    assert_eq!(*bitmap.store(), [0x0000, 0x8000]);
+}
+
+#[test]
+fn test_count_ones() {
+    let buffer = [0x00000000FFFF0000u64, 0x8000000000000001];
+    let bitmap = Bitmap::new(&buffer);
+
+    assert_eq!(bitmap.count_ones(), 18);
+    assert_eq!(bitmap.count_zeros(), 110);
+}
+
+#[test]
+fn test_parity() {
+    let mut bitmap = Bitmap::new([0u32; 3]);
+    assert!(!bitmap.parity());
+
+    bitmap.set_bit_range(3..40);
+    assert!(bitmap.parity());
+
+    bitmap.set_bit(95);
+    assert!(!bitmap.parity());
+}
+
+#[test]
+fn test_iter_rev() {
+    let buffer = [0x00000000FFFF0000u64, 0x8000000000000001];
+    let bitmap = Bitmap::new(&buffer);
+
+    let mut forward: Vec<usize> = bitmap.iter().collect();
+    forward.reverse();
+    assert_eq!(bitmap.iter_rev().collect::<Vec<_>>(), forward);
+    assert_eq!(bitmap.iter_rev().take(3).collect::<Vec<_>>(), [127, 64, 31]);
+}
+
+#[test]
+fn test_assign_minimal() {
+    let mut bitmap = Bitmap::new([0x00FFu16, 0x0F0F, 0xFFFF]);
+    let target = Bitmap::new([0x00FFu16, 0xF0F0, 0xFFFF]);
+
+    assert_eq!(bitmap.assign_minimal(&target), 1);
+    assert_eq!(*bitmap.store(), [0x00FF, 0xF0F0, 0xFFFF]);
+
+    assert_eq!(bitmap.assign_minimal(&target), 0);
+    assert_eq!(*bitmap.store(), [0x00FF, 0xF0F0, 0xFFFF]);
+}
+
+#[test]
+fn test_clear_iter() {
+    let buffer = [0b11110000u8, 0b11111111, 0b00001111];
+    let bitmap = Bitmap::new(&buffer);
+
+    assert_eq!(bitmap.clear_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 20, 21, 22, 23]);
+    assert_eq!(bitmap.clear_range_iter().collect::<Vec<_>>(), [(0, 4), (20, 4)]);
+}
+
+#[test]
+fn test_conflict_mask() {
+    let base = Bitmap::new([0b00001111u8, 0b11110000]);
+    let a = Bitmap::new([0b00111110u8, 0b11110001]);
+    let b = Bitmap::new([0b00001110u8, 0b01110001]);
+
+    let conflicts = Bitmap::conflict_mask(&base, &a, &b);
+    assert_eq!(*conflicts.store(), [0b00000000, 0b00000000]);
+    assert_eq!(conflicts.size(), 16);
+    assert_eq!(conflicts.iter().next(), None);
+}
+
+#[test]
+fn test_merge3() {
+    let base = Bitmap::new([0b00001111u8, 0b11110000]);
+    let a = Bitmap::new([0b00111111u8, 0b11110000]);
+    let b = Bitmap::new([0b00001110u8, 0b01110001]);
+
+    let merged = Bitmap::merge3(&base, &a, &b).ok().unwrap();
+    assert_eq!(*merged.store(), [0b00111110, 0b01110001]);
+
+    let c = Bitmap::new([0b00011110u8, 0b11110000]);
+    let merged = Bitmap::merge3(&base, &a, &c).ok().unwrap();
+    assert_eq!(*merged.store(), [0b00111110, 0b11110000]);
+
+    let merged = Bitmap::merge3(&base, &a, &a).ok().unwrap();
+    assert_eq!(*merged.store(), *a.store());
+}
+
+#[test]
+fn test_binary_ops() {
+    let bitmap_1: Bitmap<Vec<u64>, u64> = Bitmap::new(vec![0x00000000FFFFFFFF, 0x1]);
+    let bitmap_2: Bitmap<Vec<u64>, u64> = Bitmap::new(vec![0x0000FFFFFFFF0000]);
+
+    assert_eq!(*(&bitmap_1 & &bitmap_2).store(), [0x00000000FFFF0000]);
+    assert_eq!(*(&bitmap_1 | &bitmap_2).store(), [0x0000FFFFFFFFFFFF]);
+    assert_eq!(*(&bitmap_1 ^ &bitmap_2).store(), [0x0000FFFF0000FFFF]);
+    assert_eq!(*(bitmap_2 & bitmap_1).store(), [0x00000000FFFF0000]);
+}
+
+#[test]
+fn test_get_bit_or() {
+    let buffer = [0b10101010u8, 0b11111111];
+    let bitmap = Bitmap::new(&buffer);
+
+    assert!(bitmap.get_bit_or(1, false));
+    assert!(!bitmap.get_bit_or(2, true));
+    assert!(bitmap.get_bit_or(15, false));
+    assert!(!bitmap.get_bit_or(16, false));
+    assert!(bitmap.get_bit_or(16, true));
+    assert!(bitmap.get_bit_or(usize::MAX, true));
+}
+
+#[test]
+fn test_is_length_word_aligned() {
+    let buffer = [0xFFu8, 0xFF, 0xFF];
+
+    let bitmap = Bitmap::new(&buffer);
+    assert!(bitmap.is_length_word_aligned());
+    assert_eq!(bitmap.size(), 24);
+
+    let bitmap = Bitmap::with_bit_count(&buffer, 20);
+    assert!(!bitmap.is_length_word_aligned());
+    assert_eq!(bitmap.size(), 20);
+    assert_eq!(bitmap.bit_capacity(), 24);
+    assert_eq!(bitmap.count_ones(), 20);
+}
+
+#[test]
+fn test_assign_ignores_padding() {
+    let mut bitmap_1 = Bitmap::with_bit_count([0x0Fu8, 0x0F], 16);
+    let bitmap_2 = Bitmap::with_bit_count([0xFFu8, 0xFF], 12);
+
+    bitmap_1 |= &bitmap_2;
+    assert_eq!(*bitmap_1.store(), [0xFF, 0x0F]);
+
+    bitmap_1 &= &Bitmap::with_bit_count([0xF0u8, 0x00], 10);
+    assert_eq!(*bitmap_1.store(), [0xF0, 0x0C]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let bitmap = Bitmap::with_bit_count(vec![0x1234u16, 0xFFFF], 20);
+
+    let serialized = serde_json::to_string(&bitmap).unwrap();
+    assert_eq!(serialized, r#"{"bit_count":20,"bytes":[52,18,15,0]}"#);
+
+    let deserialized: Bitmap<Vec<u16>, u16> = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.size(), 20);
+    assert_eq!(*deserialized.store(), [0x1234, 0x000F]);
+
+    assert!(serde_json::from_str::<Bitmap<Vec<u64>, u64>>(&serialized).is_err());
+}
+
+#[test]
+fn test_resize_bits() {
+    let mut bitmap: Bitmap<Vec<u8>, u8> = Bitmap::with_capacity_bits(20);
+    assert_eq!(bitmap.size(), 0);
+    assert!(bitmap.store().capacity() >= 3);
+
+    bitmap.resize_bits(4, true);
+    assert_eq!(bitmap.size(), 4);
+    assert_eq!(bitmap.count_ones(), 4);
+
+    bitmap.resize_bits(12, false);
+    assert_eq!(bitmap.size(), 12);
+    assert_eq!(*bitmap.store(), [0b00001111, 0b00000000]);
+
+    bitmap.resize_bits(20, true);
+    assert_eq!(bitmap.size(), 20);
+    assert_eq!(*bitmap.store(), [0b00001111, 0b11110000, 0b11111111]);
+    assert_eq!(bitmap.count_ones(), 12);
+
+    bitmap.resize_bits(6, false);
+    assert_eq!(bitmap.size(), 6);
+    assert_eq!(*bitmap.store(), [0b00001111]);
+
+    bitmap.resize_bits(3, false);
+    assert_eq!(bitmap.size(), 3);
+    assert_eq!(*bitmap.store(), [0b00001111]);
+    assert_eq!(bitmap.count_ones(), 3);
+
+    bitmap.resize_bits(10, false);
+    assert_eq!(*bitmap.store(), [0b00000111, 0b00000000]);
+    assert_eq!(bitmap.count_ones(), 3);
+}
+
+#[test]
+fn test_try_for_each_set() {
+    let buffer = [0b10110010u8, 0b00000001];
+    let bitmap = Bitmap::new(&buffer);
+
+    let mut visited = Vec::new();
+    let result = bitmap.try_for_each_set(|bit_index| {
+        if visited.len() == 2 {
+            return Err(bit_index);
+        }
+
+        visited.push(bit_index);
+        Ok(())
+    });
+
+    assert_eq!(result, Err(5));
+    assert_eq!(visited, [1, 4]);
+
+    let mut visited = Vec::new();
+    let result: Result<(), ()> = bitmap.try_for_each_set(|bit_index| {
+        visited.push(bit_index);
+        Ok(())
+    });
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(visited, [1, 4, 5, 7, 8]);
+}
+
+#[test]
+fn test_active_word_range() {
+    let mut bitmap = Bitmap::new(vec![0u16; 5]);
+    assert_eq!(bitmap.active_word_range(), None);
+
+    bitmap.set_bit(20);
+    bitmap.set_bit(60);
+    assert_eq!(bitmap.active_word_range(), Some(1..4));
+    assert_eq!(bitmap.subslice(18..64).active_word_range(), Some(0..3));
+    assert_eq!(bitmap.subslice(21..60).active_word_range(), None);
+}
+
+#[test]
+fn test_any_set_any_clear() {
+    let mut bitmap = Bitmap::new(vec![0u32; 3]);
+    assert!(!bitmap.any_set());
+    assert!(bitmap.any_clear());
+
+    bitmap.set_bit(70);
+    assert!(bitmap.any_set());
+    assert!(bitmap.any_clear());
+
+    bitmap.set_bit_range(0..bitmap.size());
+    assert!(bitmap.any_set());
+    assert!(!bitmap.any_clear());
+
+    let bitmap = Bitmap::with_bit_count(vec![u32::MAX, 0x0000FFFF], 48);
+    assert!(!bitmap.any_clear());
+    assert!(!bitmap.subslice(40..48).any_clear());
+}
+
+#[test]
+fn test_from_bit_string() {
+    let bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("1100_0000 0000\n1").unwrap();
+    assert_eq!(bitmap.size(), 13);
+    assert_eq!(bitmap.store(), &[0b00000011, 0b00010000]);
+
+    let mut bitmap = Bitmap::new(vec![0u16; 3]);
+    bitmap.set_bit_range(5..19);
+    bitmap.set_bit(47);
+    let parsed = Bitmap::<Vec<u16>, u16>::from_bit_string(&format!("{}", bitmap)).unwrap();
+    assert_eq!(parsed.size(), bitmap.size());
+    assert_eq!(parsed.store(), bitmap.store());
+
+    assert_eq!(
+        Bitmap::<Vec<u8>, u8>::from_bit_string("01 2").err(),
+        Some(ParseError::InvalidCharacter { position: 3, character: '2' })
+    );
+}
+
+#[test]
+fn test_format() {
+    let bitmap = Bitmap::with_bit_count(vec![0b00100001u8, 0b00000010], 10);
+    assert_eq!(format!("{}", bitmap), "1000010001");
+    assert_eq!(format!("{:?}", bitmap), "Bitmap { size: 10, bits: 10000100 01 }");
+    assert_eq!(format!("{}", bitmap.subslice(5..10)), "10001");
+}
+
+#[test]
+fn test_atomic_bitmap() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    let bitmap = AtomicBitmap::with_bit_count(
+        (0..4).map(|_| AtomicU32::new(0)).collect::<Vec<_>>(),
+        100
+    );
+
+    let claimed_counts: Vec<usize> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let bitmap = &bitmap;
+                scope.spawn(move || {
+                    (0..100).filter(|bit_index| bitmap.try_set_bit(*bit_index, Ordering::AcqRel)).count()
+                })
+            })
+            .collect();
+
+        workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+    });
+
+    assert_eq!(claimed_counts.iter().sum::<usize>(), 100);
+    assert!((0..100).all(|bit_index| bitmap.get_bit(bit_index, Ordering::Acquire)));
+
+    assert!(bitmap.clear_bit(42, Ordering::AcqRel));
+    assert!(!bitmap.clear_bit(42, Ordering::AcqRel));
+    assert!(bitmap.try_set_bit(42, Ordering::AcqRel));
+    assert!(!bitmap.try_set_bit(42, Ordering::AcqRel));
+    assert!(bitmap.set_bit(42, Ordering::AcqRel));
+    assert_eq!(bitmap.store()[3].load(Ordering::Relaxed), 0x0000000F);
+}
+
+#[test]
+fn test_partition_indices() {
+    let bitmap = Bitmap::with_bit_count(vec![0b1001101100u16], 10);
+
+    let (set_indices, clear_indices) = bitmap.partition_indices();
+    assert_eq!(set_indices, [2, 3, 5, 6, 9]);
+    assert_eq!(clear_indices, [0, 1, 4, 7, 8]);
+
+    let mut all_indices = [set_indices, clear_indices].concat();
+    all_indices.sort();
+    assert_eq!(all_indices, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_remove_short_set_runs() {
+    let mut bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("0100 1111 0011 0").unwrap();
+    bitmap.remove_short_set_runs(3);
+    assert_eq!(format!("{}", bitmap), "0000111100000");
+
+    bitmap.remove_short_set_runs(5);
+    assert!(bitmap.is_empty());
+}
+
+#[test]
+fn test_fill_short_clear_runs() {
+    let mut bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("0110 1101 1000 1100").unwrap();
+    bitmap.fill_short_clear_runs(1);
+    assert_eq!(format!("{}", bitmap), "0111111110001100");
+
+    bitmap.fill_short_clear_runs(3);
+    assert_eq!(format!("{}", bitmap), "0111111111111100");
+}
+
+#[test]
+fn test_from_iter_bool() {
+    let bits = [true, false, false, true, true, false, true, false, false, true];
+    let bitmap: Bitmap<Vec<u8>, u8> = bits.iter().copied().collect();
+
+    assert_eq!(bitmap.size(), 10);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [0, 3, 4, 6, 9]);
+    assert!((0..bits.len()).all(|bit_index| bitmap.get_bit(bit_index) == bits[bit_index]));
+
+    let empty: Bitmap<Vec<u8>, u8> = std::iter::empty::<bool>().collect();
+    assert_eq!(empty.size(), 0);
+}
+
+#[test]
+fn test_from_iter_usize() {
+    let bitmap: Bitmap<Vec<u16>, u16> = [17, 3, 40, 3, 17].into_iter().collect();
+
+    assert_eq!(bitmap.size(), 48);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [3, 17, 40]);
+
+    let round_trip: Bitmap<Vec<u16>, u16> = bitmap.iter().collect();
+    assert_eq!(round_trip.store(), bitmap.store());
+
+    let empty: Bitmap<Vec<u16>, u16> = std::iter::empty::<usize>().collect();
+    assert_eq!(empty.size(), 0);
+}
+
+#[test]
+fn test_take_first_set() {
+    let bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("0011 0000 0100 1").unwrap();
+
+    let prefix = bitmap.take_first_set(2);
+    assert_eq!(prefix.size(), bitmap.size());
+    assert_eq!(format!("{}", prefix), "0011000000000");
+
+    assert_eq!(format!("{}", bitmap.take_first_set(3)), "0011000001000");
+    assert_eq!(format!("{}", bitmap.take_first_set(10)), format!("{}", bitmap));
+    assert!(bitmap.take_first_set(0).is_empty());
+}
+
+#[test]
+fn test_validate_capacity() {
+    let mut bitmap = Bitmap::new(vec![0u32; 2]);
+    bitmap.set_bit_range(0..20);
+    assert_eq!(bitmap.validate_capacity(20), Ok(()));
+    assert_eq!(bitmap.validate_capacity(100), Ok(()));
+
+    bitmap.set_bit(45);
+    assert_eq!(bitmap.validate_capacity(40), Err(BitmapError::CapacityExceeded { capacity: 40, bit_index: 45 }));
+    assert_eq!(bitmap.validate_capacity(10), Err(BitmapError::CapacityExceeded { capacity: 10, bit_index: 10 }));
+}
+
+#[test]
+fn test_extend() {
+    let mut bitmap = Bitmap::new(Vec::<u16>::with_capacity(4));
+    bitmap.extend(std::iter::empty());
+    assert_eq!(bitmap.size(), 0);
+    assert_eq!(bitmap.store().capacity(), 4);
+
+    bitmap.extend([3, 3, 9]);
+    assert_eq!(bitmap.size(), 16);
+    assert_eq!(bitmap.store(), &[0b0000001000001000]);
+
+    bitmap.extend([50]);
+    assert_eq!(bitmap.size(), 64);
+    assert_eq!(bitmap.store(), &[0b0000001000001000, 0, 0, 0b0000000000000100]);
+}
+
+#[test]
+fn test_eq_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let lhs = Bitmap::with_bit_count(vec![0b0000_0101u8, 0b1111_0010], 12);
+    let rhs = Bitmap::with_bit_count(vec![0b1010_0010_0000_0101u16], 12);
+    let wide = Bitmap::with_bit_count(vec![0b0010_0000_0101u64], 12);
+
+    assert_eq!(lhs, rhs);
+    assert_eq!(lhs, wide);
+    assert_eq!(hash_of(&lhs), hash_of(&rhs));
+    assert_eq!(hash_of(&lhs), hash_of(&wide));
+
+    assert_ne!(lhs, Bitmap::with_bit_count(vec![0b0000_0101u8, 0b0000_0010], 13));
+    assert_ne!(lhs, Bitmap::with_bit_count(vec![0b0000_0101u8, 0b0000_0011], 12));
+
+    let buffer = [0b0010_1000u8, 0b0000_0000];
+    assert_eq!(lhs.subslice(0..6), crate::slice::BitmapSlice::new(&buffer, 3..9));
+    assert_eq!(hash_of(&lhs.subslice(0..6)), hash_of(&crate::slice::BitmapSlice::new(&buffer, 3..9)));
+}
+
+#[test]
+fn test_be_bytes() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0u16; 2], 20);
+    bitmap.set_bit(0);
+    assert_eq!(bitmap.to_be_bytes(), [0x80, 0x00, 0x00]);
+
+    bitmap.set_bit_range(6..10);
+    bitmap.set_bit(19);
+    let bytes = bitmap.to_be_bytes();
+    assert_eq!(bytes, [0b1000_0011, 0b1100_0000, 0b0001_0000]);
+
+    let round_trip = Bitmap::<Vec<u16>, u16>::from_be_bytes(&bytes, 20);
+    assert_eq!(round_trip, bitmap);
+    assert_eq!(Bitmap::<Vec<u8>, u8>::from_be_bytes(&bytes, 20), bitmap);
+}
+
+#[test]
+fn test_set_relations() {
+    let small = Bitmap::with_bit_count(vec![0b0000_0110u8], 5);
+    let large = Bitmap::new(vec![0b0000_1110u8, 0b1000_0000, 0b0000_0001]);
+    let other = Bitmap::new(vec![0b0000_0001u8, 0b0000_0000, 0b0000_0010]);
+
+    assert!(small.is_subset_of(&large));
+    assert!(large.is_superset_of(&small));
+    assert!(!large.is_subset_of(&small));
+    assert!(!small.is_superset_of(&large));
+    assert!(small.is_subset_of(&small));
+
+    assert!(small.is_disjoint(&other));
+    assert!(large.is_disjoint(&other));
+    assert!(!large.is_disjoint(&small));
+
+    let padded = Bitmap::with_bit_count(vec![0b1110_0110u8], 5);
+    assert!(padded.is_subset_of(&small));
+    assert!(padded.is_disjoint(&other));
+    assert!(large.subslice(1..4).is_superset_of(&small.subslice(1..3)));
+    assert!(!large.subslice(3..5).is_superset_of(&small.subslice(1..3)));
+}
+
+#[test]
+fn test_change_counts() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0u32; 3], 70);
+    bitmap.set_bit_range(10..40);
+    let before = bitmap.snapshot();
+
+    bitmap.clear_bit_range(30..35);
+    bitmap.set_bit_range(60..70);
+    bitmap.set_bit(0);
+    let after = bitmap.snapshot();
+
+    assert_eq!(before.change_counts(&after), (11, 5));
+    assert_eq!(after.change_counts(&before), (5, 11));
+    assert_eq!(after.change_counts(&after), (0, 0));
+}
+
+#[test]
+fn test_permute() {
+    let bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("1101").unwrap();
+    let perm = [2, 0, 3, 1];
+
+    let permuted = bitmap.permute(&perm);
+    let expected: Bitmap<Vec<u8>, u8> = perm.iter().map(|source_index| bitmap.get_bit(*source_index)).collect();
+    assert_eq!(permuted, expected);
+    assert_eq!(format!("{}", permuted), "0111");
+
+    assert_eq!(bitmap.permute(&[3, 2, 1, 0]), Bitmap::<Vec<u8>, u8>::from_bit_string("1011").unwrap());
+}
+
+#[test]
+#[should_panic]
+fn test_permute_invalid() {
+    let bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("1101").unwrap();
+    bitmap.permute(&[0, 1, 2, 4]);
+}
+
+#[test]
+fn test_checked_access() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0u8; 2], 12);
+
+    assert_eq!(bitmap.set_bit_checked(11), Ok(()));
+    assert_eq!(bitmap.get_bit_checked(11), Ok(true));
+    assert_eq!(bitmap.toggle_bit_checked(3), Ok(()));
+    assert_eq!(bitmap.get_bit_checked(3), Ok(true));
+    assert_eq!(bitmap.clear_bit_checked(3), Ok(()));
+    assert_eq!(bitmap.get_bit_checked(3), Ok(false));
+
+    let error = OutOfBoundsError { bit_index: 12, size: 12 };
+    assert_eq!(bitmap.set_bit_checked(12), Err(error));
+    assert_eq!(bitmap.clear_bit_checked(12), Err(error));
+    assert_eq!(bitmap.toggle_bit_checked(12), Err(error));
+    assert_eq!(bitmap.get_bit_checked(12), Err(error));
+    assert_eq!(bitmap.store(), &[0b0000_0000, 0b0000_1000]);
+    assert_eq!(error.to_string(), "Bit index 12 is out of bounds for bitmap of size 12");
+}
+
+#[test]
+fn test_count_runs_at_least() {
+    let bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("00 1 00000 11 00000000 1").unwrap();
+
+    assert_eq!(bitmap.count_clear_runs_at_least(5), 2);
+    assert_eq!(bitmap.count_clear_runs_at_least(0), 3);
+    assert_eq!(bitmap.count_clear_runs_at_least(9), 0);
+    assert_eq!(bitmap.count_set_runs_at_least(1), 3);
+    assert_eq!(bitmap.count_set_runs_at_least(2), 1);
+}
+
+#[test]
+fn test_set_bit_len() {
+    let mut buffer = [0b1000_0001u8, 0b0000_0001];
+    let mut bitmap = Bitmap::new(&mut buffer[..]);
+
+    assert_eq!(bitmap.set_bit_len(7), Ok(()));
+    assert_eq!(bitmap.size(), 7);
+    assert_eq!(bitmap.count_ones(), 1);
+
+    assert_eq!(bitmap.set_bit_len(17), Err(BitmapError::LengthExceedsCapacity { bit_count: 17, capacity: 16 }));
+    assert_eq!(bitmap.size(), 7);
+
+    assert_eq!(bitmap.set_bit_len(16), Ok(()));
+    assert_eq!(bitmap.count_ones(), 3);
+}
+
+#[test]
+fn test_set_all_clear_all() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0u16; 3], 20);
+    bitmap.set_all();
+    assert!(bitmap.is_full());
+    assert_eq!(bitmap.count_ones(), 20);
+    assert_eq!(bitmap.store()[2], 0);
+
+    bitmap.clear_all();
+    assert!(bitmap.is_empty());
+
+    let mut bitmap = Bitmap::with_bit_count(vec![0u16; 2], 20);
+    bitmap.set_all();
+    bitmap.set_bit_len(32).unwrap();
+    assert_eq!(bitmap.count_ones(), 20);
+
+    bitmap.set_bit(25);
+    bitmap.set_bit_len(20).unwrap();
+    bitmap.clear_all();
+    bitmap.set_bit_len(32).unwrap();
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [25]);
+}
+
+#[test]
+fn test_can_satisfy() {
+    let bitmap = Bitmap::<Vec<u8>, u8>::from_bit_string("000 1 00000 1 0000000 1").unwrap();
+
+    assert!(bitmap.can_satisfy(&[]));
+    assert!(bitmap.can_satisfy(&[3, 5, 7]));
+    assert!(bitmap.can_satisfy(&[2, 4, 1, 6]));
+    assert!(!bitmap.can_satisfy(&[8]));
+    assert!(!bitmap.can_satisfy(&[5, 5, 5]));
+    assert_eq!(bitmap.count_zeros(), 15);
+}
+
+#[test]
+fn test_highest_active_word() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0u8; 4], 29);
+    assert_eq!(bitmap.highest_active_word(), None);
+
+    bitmap.set_bit(3);
+    bitmap.set_bit(17);
+    assert_eq!(bitmap.highest_active_word(), Some(2));
+    assert_eq!(bitmap.subslice(2..16).highest_active_word(), Some(0));
+    assert_eq!(bitmap.subslice(4..16).highest_active_word(), None);
+
+    let padded = Bitmap::with_bit_count(vec![0x01u8, 0x80], 12);
+    assert_eq!(padded.highest_active_word(), Some(0));
+}
+
+#[test]
+fn test_checked_assign() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0b1100u8], 4);
+    let rhs = Bitmap::with_bit_count(vec![0b1010u8], 4);
+    let longer = Bitmap::with_bit_count(vec![0b1010u8], 5);
+
+    let mismatch = Err(BitmapError::LengthMismatch { lhs_bit_count: 4, rhs_bit_count: 5 });
+    assert_eq!(bitmap.checked_and_assign(&longer), mismatch);
+    assert_eq!(bitmap.checked_or_assign(&longer), mismatch);
+    assert_eq!(bitmap.checked_xor_assign(&longer), mismatch);
+    assert_eq!(bitmap.store(), &[0b1100]);
+
+    assert_eq!(bitmap.checked_xor_assign(&rhs), Ok(()));
+    assert_eq!(bitmap.store(), &[0b0110]);
+    assert_eq!(bitmap.checked_or_assign(&rhs), Ok(()));
+    assert_eq!(bitmap.store(), &[0b1110]);
+    assert_eq!(bitmap.checked_and_assign(&rhs), Ok(()));
+    assert_eq!(bitmap.store(), &[0b1010]);
+}
+
+#[test]
+fn test_gray_code() {
+    let small = Bitmap::with_bit_count(vec![0b1011_0110u8], 8);
+    assert_eq!(small.gray_encode().store(), &[0b1011_0110u8 ^ (0b1011_0110u8 >> 1)]);
+
+    let mut bitmap = Bitmap::with_bit_count(vec![0u32; 4], 113);
+    for bit_index in [0, 1, 5, 31, 32, 33, 64, 90, 91, 92, 112] {
+        bitmap.set_bit(bit_index);
+    }
+
+    let encoded = bitmap.gray_encode();
+    assert_eq!(encoded.size(), bitmap.size());
+    for bit_index in 0..bitmap.size() {
+        assert_eq!(encoded.get_bit(bit_index), bitmap.get_bit(bit_index) ^ bitmap.get_bit_or(bit_index + 1, false));
+    }
+
+    assert_eq!(encoded.gray_decode(), bitmap);
+    assert_eq!(bitmap.gray_decode().gray_encode(), bitmap);
+}
+
+#[test]
+fn test_msb0_order() {
+    use crate::store::Msb0;
+
+    let mut bitmap = Bitmap::with_order(vec![0u8; 3], Msb0);
+    bitmap.set_bit(0);
+    bitmap.set_bit_range(6..11);
+    bitmap.set_bit(23);
+    assert_eq!(bitmap.store(), &[0b1000_0011, 0b1110_0000, 0b0000_0001]);
+    assert_eq!(bitmap.to_be_bytes(), [0b1000_0011, 0b1110_0000, 0b0000_0001]);
+
+    assert_eq!(bitmap.find_first_set(), Some(0));
+    assert_eq!(bitmap.find_next_set_from(1), Some(6));
+    assert_eq!(bitmap.find_next_clear_from(6), Some(11));
+    assert_eq!(bitmap.find_last_set(), Some(23));
+    assert_eq!(bitmap.range_iter().collect::<Vec<_>>(), [(0, 1), (6, 5), (23, 1)]);
+    assert_eq!(bitmap.count_ones_in_range(3..9), 3);
+    assert_eq!(bitmap.select(3), Some(8));
+
+    let lsb0: Bitmap<Vec<u8>, u8> = bitmap.iter().collect();
+    let mut rotated = bitmap.snapshot();
+    rotated.rotate_left(5);
+    let mut rotated_lsb0 = Bitmap::with_bit_count(lsb0.store().clone(), 24);
+    rotated_lsb0.rotate_left(5);
+    assert_eq!(rotated, rotated_lsb0);
+
+    let mut wire = [0x80u8, 0x01];
+    let view = Bitmap::with_order(&wire[..], Msb0);
+    assert_eq!(view.iter().collect::<Vec<_>>(), [0, 15]);
+
+    Bitmap::with_order(&mut wire[..], Msb0).set_bit(1);
+    assert_eq!(wire, [0xC0, 0x01]);
+
+    let parsed = Bitmap::<Vec<u8>, u8, Msb0>::from_bit_string("1100 0000 01").unwrap();
+    assert_eq!(parsed.store(), &[0b1100_0000, 0b0100_0000]);
+    assert_eq!(Bitmap::<Vec<u8>, u8, Msb0>::from_set_indices(10, &[0, 1, 9]), parsed);
+    assert_eq!(Bitmap::<Vec<u8>, u8, Msb0>::from_bytes(&parsed.to_bytes(), 10).store(), parsed.store());
+    assert_eq!(Bitmap::<Vec<u8>, u8, Msb0>::from_delta_varints(&parsed.to_delta_varints(), 10).unwrap().store(), parsed.store());
+}
+
+#[test]
+fn test_msb0_combine_words() {
+    use crate::store::{BitOrder, BitStore, ByteArray, Lsb0, Msb0};
+
+    fn build<B: BitStore, O: BitOrder>(bit_count: usize, seed: usize, order: O) -> Bitmap<Vec<B>, B, O> {
+        let slot_count = crate::polyfill::div_ceil(bit_count, B::BIT_COUNT);
+        let mut bitmap = Bitmap::with_order_and_bit_count(vec![B::MAX; slot_count], bit_count, order);
+        for bit_index in 0..bit_count {
+            if ((bit_index * 7 + seed) % 3) != 0 {
+                bitmap.clear_bit(bit_index);
+            }
+        }
+
+        bitmap
+    }
+
+    fn check<B: BitStore>(bit_count: usize) {
+        let (a, b) = (build::<B, _>(bit_count, 1, Msb0), build::<B, _>(bit_count, 2, Msb0));
+        let (a_lsb0, b_lsb0) = (build::<B, _>(bit_count, 1, Lsb0), build::<B, _>(bit_count, 2, Lsb0));
+        assert_eq!(a, a_lsb0);
+
+        assert_eq!(&a & &b, &a_lsb0 & &b_lsb0);
+        assert_eq!(&a | &b, &a_lsb0 | &b_lsb0);
+        assert_eq!(&a ^ &b, &a_lsb0 ^ &b_lsb0);
+
+        let (mut and, mut or, mut xor) = (a.clone(), a.clone(), a.clone());
+        and &= &b;
+        or |= &b;
+        xor ^= &b;
+        assert_eq!(and, &a_lsb0 & &b_lsb0);
+        assert_eq!(or, &a_lsb0 | &b_lsb0);
+        assert_eq!(xor, &a_lsb0 ^ &b_lsb0);
+
+        let (mut and, mut or, mut xor) = (a.clone(), a.clone(), a.clone());
+        and.checked_and_assign(&b).unwrap();
+        or.checked_or_assign(&b).unwrap();
+        xor.checked_xor_assign(&b).unwrap();
+        assert_eq!(and, &a_lsb0 & &b_lsb0);
+        assert_eq!(or, &a_lsb0 | &b_lsb0);
+        assert_eq!(xor, &a_lsb0 ^ &b_lsb0);
+
+        let mut assigned = a.clone();
+        assigned.assign_minimal(&b);
+        assert_eq!(assigned, b);
+
+        let mut extended = build::<B, _>(bit_count - 3, 1, Msb0);
+        let mut extended_lsb0 = build::<B, _>(bit_count - 3, 1, Lsb0);
+        extended.union_extend(&b);
+        extended_lsb0.union_extend(&b_lsb0);
+        assert_eq!(extended, extended_lsb0);
+
+        assert_eq!(a.boundaries(), a_lsb0.boundaries());
+        assert_eq!(a.gray_encode(), a_lsb0.gray_encode());
+        assert_eq!(a.gray_decode(), a_lsb0.gray_decode());
+    }
+
+    for bit_count in [4, 13, 21, 70] {
+        check::<u8>(bit_count);
+        check::<u64>(bit_count);
+        check::<ByteArray<3>>(bit_count);
+    }
+}
+
+#[test]
+fn test_byte_array_store() {
+    use crate::slice::{BitmapSlice, BitmapSliceMut};
+    use crate::store::{BitStore, ByteArray};
+
+    assert_eq!(ByteArray::<3>::create_range_mask(4, 13), ByteArray([0xF0, 0xFF, 0x01]));
+    assert_eq!(ByteArray::<3>::create_range_mask(0, 24), ByteArray::<3>::MAX);
+    assert_eq!(ByteArray::<3>::create_bit_mask(17), ByteArray([0x00, 0x00, 0x02]));
+    assert_eq!(ByteArray([0x00, 0x10, 0x00]).trailing_zeros(), 12);
+    assert_eq!(ByteArray([0x00, 0x10, 0x00]).leading_zeros(), 11);
+    assert_eq!(ByteArray([0x81, 0x00, 0x00]).shift_left(9), ByteArray([0x00, 0x02, 0x01]));
+    assert_eq!(ByteArray([0x00, 0x02, 0x01]).shift_right(9), ByteArray([0x81, 0x00, 0x00]));
+
+    let mut bitmap = Bitmap::new(vec![ByteArray::<3>::ZERO; 3]);
+    let mut reference = Bitmap::new(vec![0u64; 2]);
+    reference.set_bit_len(72).unwrap();
+    for range in [5..30, 40..41, 47..70] {
+        bitmap.set_bit_range(range.clone());
+        reference.set_bit_range(range);
+    }
+
+    bitmap.clear_bit_range(20..44);
+    reference.clear_bit_range(20..44);
+    bitmap.toggle_bit(71);
+    reference.toggle_bit(71);
+
+    assert_eq!(bitmap, reference);
+    assert_eq!(bitmap.count_ones(), reference.count_ones());
+    assert_eq!(bitmap.find_next_set_from(20), Some(47));
+    assert_eq!(bitmap.find_last_clear(), Some(70));
+    assert_eq!(bitmap.range_iter().collect::<Vec<_>>(), reference.range_iter().collect::<Vec<_>>());
+    assert_eq!(bitmap.to_bytes(), reference.to_bytes());
+
+    bitmap.rotate_left(13);
+    reference.rotate_left(13);
+    assert_eq!(bitmap, reference);
+
+    let buffer = vec![ByteArray::<3>::ZERO; 3];
+    assert_eq!(BitmapSlice::new(&buffer, 0..72).find_first_set(), None);
+    assert_eq!(BitmapSlice::new(&buffer, 0..72).find_last_set(), None);
+
+    let mut buffer = vec![ByteArray::<5>::MAX; 4];
+    assert_eq!(BitmapSlice::new(&buffer, 0..160).find_first_clear(), None);
+    BitmapSliceMut::new(&mut buffer, 0..160).clear_bit(130);
+    assert_eq!(BitmapSlice::new(&buffer, 0..160).find_first_clear(), Some(130));
+    assert_eq!(BitmapSlice::new(&buffer, 3..160).find_next_clear_from(0), Some(127));
+}
+
+#[test]
+fn test_to_bytes() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0u32; 2], 45);
+    bitmap.set_bit(0);
+    bitmap.set_bit_range(5..27);
+    bitmap.set_bit(44);
+
+    let bytes = bitmap.to_bytes();
+    assert_eq!(bytes, [0b1110_0001, 0xFF, 0xFF, 0b0000_0111, 0x00, 0b0001_0000]);
+    assert_eq!(Bitmap::<Vec<u32>, u32>::from_bytes(&bytes, 45), bitmap);
+    assert_eq!(Bitmap::<Vec<u8>, u8>::from_bytes(&bytes, 45), bitmap);
+    assert_eq!(Bitmap::<Vec<u8>, u8>::from_bytes(&bytes, 45).to_bytes(), bytes);
+    assert_eq!(bitmap.subslice(5..13).to_bytes(), [0xFF]);
+    assert_eq!(bitmap.subslice(3..10).to_bytes(), [0b0111_1100]);
+
+    let padded = Bitmap::with_bit_count(vec![u64::MAX], 10);
+    assert_eq!(padded.to_bytes(), [0xFF, 0b0000_0011]);
+}
+
+#[test]
+fn test_boundaries() {
+    let bitmap = Bitmap::new(vec![0b0011_0100u8]);
+    assert_eq!(bitmap.boundaries().store(), &[0b0101_1100]);
+
+    let mut bitmap = Bitmap::with_bit_count(vec![0u16; 3], 40);
+    bitmap.set_bit_range(0..3);
+    bitmap.set_bit_range(15..33);
+    let boundaries = bitmap.boundaries();
+    assert_eq!(boundaries.iter().collect::<Vec<_>>(), [0, 3, 15, 33]);
+}
+
+#[test]
+fn test_journaled_bitmap() {
+    let mut bitmap = Bitmap::new(vec![0u8; 3]);
+    bitmap.set_bit_range(4..10);
+    let original = bitmap.as_slice().to_bytes();
+
+    let mut journaled = JournaledBitmap::new(bitmap);
+    let checkpoint = journaled.checkpoint();
+    journaled.set_bit_range(2..14);
+    let after_set = journaled.as_bitmap().as_slice().to_bytes();
+
+    journaled.clear_bit_range(6..20);
+    journaled.toggle_bit_range(0..24);
+    assert_eq!(journaled.journal_len(), 3);
+
+    journaled.rollback(checkpoint + 1);
+    assert_eq!(journaled.as_bitmap().as_slice().to_bytes(), after_set);
+
+    journaled.rollback(checkpoint);
+    assert_eq!(journaled.journal_len(), 0);
+    assert_eq!(journaled.count_ones(), 6);
+    assert_eq!(journaled.into_inner().as_slice().to_bytes(), original);
+}
+
+#[test]
+fn test_allocate_buddy() {
+    let mut bitmap = Bitmap::new(vec![0u8; 4]);
+    bitmap.set_bit(1);
+    bitmap.set_bit(9);
+
+    let mut blocks = Vec::new();
+    while let Some(block_start) = bitmap.allocate_buddy(2) {
+        assert_eq!(block_start % 4, 0);
+        blocks.push(block_start);
+    }
+
+    assert_eq!(blocks, vec![4, 12, 16, 20, 24, 28]);
+    assert_eq!(bitmap.count_ones(), 2 + 4 * blocks.len());
+    assert_eq!(bitmap.allocate_buddy(6), None);
+
+    bitmap.free_buddy(16, 2);
+    bitmap.free_buddy(20, 2);
+    assert_eq!(bitmap.allocate_buddy(3), Some(16));
+    assert_eq!(bitmap.allocate_buddy(2), None);
+}
+
+#[test]
+#[should_panic(expected = "Invalid buddy order (200)")]
+fn test_free_buddy_invalid_order() {
+    let mut bitmap = Bitmap::new(vec![0u8; 4]);
+    bitmap.free_buddy(0, 200);
+}
+
+#[test]
+fn test_keep_range() {
+    for range in [13..45, 16..48, 0..0, 20..20, 0..62, 5..9] {
+        let mut bitmap = Bitmap::with_bit_count(vec![u16::MAX; 4], 62);
+        let mut expected = bitmap.snapshot();
+        expected.clear_bit_range(0..range.start);
+        expected.clear_bit_range(range.end..62);
+
+        bitmap.keep_range(range.clone());
+        assert_eq!(bitmap, expected);
+        assert_eq!(bitmap.count_ones(), range.len());
+    }
+}
+
+#[test]
+fn test_word_occupancy_bitmap() {
+    let mut bitmap = Bitmap::new(vec![0u16; 3]);
+    bitmap.set_bit(3);
+    bitmap.set_bit(47);
+
+    let occupancy = bitmap.word_occupancy_bitmap();
+    assert_eq!(occupancy.size(), 3);
+    assert_eq!(occupancy.iter().collect::<Vec<_>>(), [0, 2]);
+
+    let padded = Bitmap::with_bit_count(vec![0u8, 0b0000_1000], 12);
+    assert_eq!(padded.word_occupancy_bitmap().iter().collect::<Vec<_>>(), [1]);
+
+    let padded = Bitmap::with_bit_count(vec![0u8, 0b1111_0000], 12);
+    assert_eq!(padded.word_occupancy_bitmap().count_ones(), 0);
+}
+
+#[test]
+fn test_zeroed() {
+    use crate::store::bits_to_slots;
+
+    const SLOT_COUNT: usize = bits_to_slots::<u16>(100);
+    const EMPTY: Bitmap<[u16; SLOT_COUNT], u16> = Bitmap::zeroed();
+
+    assert_eq!(SLOT_COUNT, 7);
+    assert_eq!(bits_to_slots::<u16>(96), 6);
+    assert_eq!(bits_to_slots::<u8>(0), 0);
+
+    let mut bitmap = EMPTY;
+    assert_eq!(bitmap.size(), 112);
+    assert!(bitmap.is_empty());
+
+    bitmap.set_bit(111);
+    assert_eq!(bitmap.find_first_set(), Some(111));
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn test_majority() {
+    let a = Bitmap::new(vec![0b1110_1000u8, 0b0000_0001]);
+    let b = Bitmap::new(vec![0b1101_0100u8, 0b0000_0001]);
+    let c = Bitmap::with_bit_count(vec![0b1011_0010u8, 0b1111_1111], 9);
+
+    let majority = Bitmap::majority(&a, &b, &c);
+    assert_eq!(majority.size(), 16);
+    assert_eq!(majority.iter().collect::<Vec<_>>(), [4, 5, 6, 7, 8]);
+    assert_eq!(Bitmap::majority(&a, &a, &c), a);
+}
+
+#[test]
+fn test_block_popcounts() {
+    let bitmap = Bitmap::new(vec![0x8000_0F00_0000_0007u64]);
+    assert_eq!(bitmap.block_popcounts(16).collect::<Vec<_>>(), [(0, 3), (16, 0), (32, 4), (48, 1)]);
+    assert_eq!(bitmap.subslice(4..64).block_popcounts(25).collect::<Vec<_>>(), [(0, 0), (25, 4), (50, 1)]);
+
+    let mut blocks = bitmap.block_popcounts(10);
+    assert_eq!(blocks.next(), Some((0, 3)));
+    assert_eq!(blocks.last(), Some((60, 1)));
+}
+
+#[test]
+fn test_into_iter() {
+    let buffer = [0x00000000FFFF0000u64, 0x8000000000000001];
+    let bitmap = Bitmap::new(&buffer);
+
+    let mut set_bits = Vec::new();
+    for bit_index in &bitmap {
+        set_bits.push(bit_index);
+    }
+
+    assert_eq!(set_bits, bitmap.iter().collect::<Vec<_>>());
+
+    let slice = bitmap.subslice(20..70);
+    assert_eq!((&slice).into_iter().collect::<Vec<_>>(), slice.iter().collect::<Vec<_>>());
+    assert_eq!(slice.into_iter().next_back(), Some(44));
+}
+
+#[test]
+fn test_find_range_in_range() {
+    let mut bitmap = Bitmap::new(vec![0u16; 4]);
+    bitmap.set_bit_range(10..30);
+    bitmap.set_bit_range(40..42);
+
+    assert_eq!(bitmap.find_next_set_range_in_range(0..64), Some((10, 20)));
+    assert_eq!(bitmap.find_next_set_range_in_range(15..25), Some((15, 10)));
+    assert_eq!(bitmap.find_next_set_range_in_range(30..41), Some((40, 1)));
+    assert_eq!(bitmap.find_next_set_range_in_range(30..40), None);
+    assert_eq!(bitmap.find_next_set_range_in_range(20..20), None);
+
+    assert_eq!(bitmap.find_next_clear_range_in_range(0..64), Some((0, 10)));
+    assert_eq!(bitmap.find_next_clear_range_in_range(12..35), Some((30, 5)));
+    assert_eq!(bitmap.find_next_clear_range_in_range(41..64), Some((42, 22)));
+    assert_eq!(bitmap.find_next_clear_range_in_range(10..30), None);
+}
+
+#[test]
+fn test_steal_from() {
+    let mut bitmap = Bitmap::new(vec![0u8; 4]);
+    for bit_index in [3, 12, 20, 31] {
+        bitmap.set_bit(bit_index);
+    }
+
+    assert_eq!(bitmap.steal_from(13), Some(20));
+    assert_eq!(bitmap.steal_from(13), Some(31));
+    assert_eq!(bitmap.steal_from(13), Some(3));
+    assert_eq!(bitmap.steal_from(100), Some(12));
+    assert_eq!(bitmap.steal_from(0), None);
+
+    bitmap.set_bit_range(6..8);
+    assert_eq!(bitmap.steal(), Some(6));
+    assert_eq!(bitmap.steal(), Some(7));
+    assert_eq!(bitmap.steal(), None);
+}
+
+#[test]
+fn test_delta_varints() {
+    let mut bitmap = Bitmap::new(vec![0u64; 160]);
+    for bit_index in [3, 100, 10000] {
+        bitmap.set_bit(bit_index);
+    }
+
+    let encoded = bitmap.to_delta_varints();
+    assert_eq!(encoded, [3, 96, 0xAB, 0x4D]);
+
+    let decoded = Bitmap::<Vec<u64>, u64>::from_delta_varints(&encoded, bitmap.size()).unwrap();
+    assert_eq!(decoded, bitmap);
+
+    let mut dense = Bitmap::new(vec![0u8; 2]);
+    dense.set_bit_range(0..3);
+    dense.set_bit(15);
+    assert_eq!(dense.to_delta_varints(), [0, 0, 0, 12]);
+    assert_eq!(Bitmap::<Vec<u8>, u8>::from_delta_varints(&dense.to_delta_varints(), 16).unwrap(), dense);
+}
+
+#[test]
+fn test_delta_varints_errors() {
+    let mut encoded = vec![0x80u8; 19];
+    encoded.push(0x01);
+    assert!(matches!(Bitmap::<Vec<u8>, u8>::from_delta_varints(&encoded, 16), Err(DecodeError::VarintOverflow { .. })));
+    assert_eq!(Bitmap::<Vec<u8>, u8>::from_delta_varints(&[3, 0x80], 16), Err(DecodeError::TruncatedVarint { byte_count: 2 }));
+    assert_eq!(Bitmap::<Vec<u8>, u8>::from_delta_varints(&[3, 12], 16),
+               Err(DecodeError::BitIndexOutOfRange { bit_index: 16, bit_count: 16, position: 1 }));
+}
+
+#[test]
+fn test_allocate_range() {
+    let mut bitmap = Bitmap::new(vec![0u8; 4]);
+    bitmap.set_bit_range(2..5);
+    bitmap.set_bit(9);
+    bitmap.set_bit_range(12..20);
+
+    assert_eq!(bitmap.allocate_range(3), Some(5));
+    assert_eq!(bitmap.allocate_range(3), Some(20));
+    assert_eq!(bitmap.allocate_range(2), Some(0));
+    assert_eq!(bitmap.allocate_range(2), Some(10));
+    assert_eq!(bitmap.allocate_range(10), None);
+    assert_eq!(bitmap.allocate_range(9), Some(23));
+    assert_eq!(bitmap.find_first_clear(), Some(8));
+
+    bitmap.free_range(5, 3);
+    assert_eq!(bitmap.allocate_range(4), Some(5));
+    assert_eq!(bitmap.allocate_range(1), None);
+    assert!(bitmap.is_full());
+}
+
+#[test]
+fn test_allocate_range_best_fit() {
+    let mut bitmap = Bitmap::new(vec![u8::MAX; 4]);
+    bitmap.clear_bit_range(1..6);
+    bitmap.clear_bit_range(8..11);
+    bitmap.clear_bit_range(14..18);
+    bitmap.clear_bit_range(20..23);
+
+    assert_eq!(bitmap.allocate_range_best_fit(3), Some(8));
+    assert_eq!(bitmap.allocate_range_best_fit(3), Some(20));
+    assert_eq!(bitmap.allocate_range_best_fit(2), Some(14));
+    assert_eq!(bitmap.allocate_range_best_fit(2), Some(16));
+    assert_eq!(bitmap.allocate_range_best_fit(4), Some(1));
+
+    let snapshot = bitmap.snapshot();
+    assert_eq!(bitmap.allocate_range_best_fit(2), None);
+    assert_eq!(bitmap, snapshot);
+    assert_eq!(bitmap.allocate_range_best_fit(1), Some(5));
+}
+
+#[test]
+fn test_union_extend() {
+    let mut bitmap = Bitmap::new(vec![0x0000_00F0u32]);
+    let other = Bitmap::new(vec![0x0000_0101u32, 0x8000_0001]);
+
+    bitmap.union_extend(&other);
+    assert_eq!(bitmap.size(), 64);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [0, 4, 5, 6, 7, 8, 32, 63]);
+
+    let short = Bitmap::with_bit_count(vec![u32::MAX], 4);
+    bitmap.union_extend(&short);
+    assert_eq!(bitmap.size(), 64);
+    assert_eq!(bitmap.count_ones(), 11);
+}
+
+#[test]
+fn test_from_set_indices() {
+    let bitmap = Bitmap::<Vec<u16>, u16>::from_set_indices(40, &[3, 17, 3, 20]);
+    assert_eq!(bitmap.size(), 40);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [3, 17, 20]);
+
+    let bitmap = Bitmap::<Vec<u16>, u16>::clear_indices(40, &[0, 39]);
+    assert_eq!(bitmap.size(), 40);
+    assert_eq!(bitmap.clear_iter().collect::<Vec<_>>(), [0, 39]);
+    assert_eq!(bitmap.count_ones(), 38);
+
+    assert!(Bitmap::<Vec<u8>, u8>::from_set_indices(0, &[]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_from_set_indices_out_of_range() {
+    Bitmap::<Vec<u8>, u8>::from_set_indices(10, &[10]);
+}
+
+#[test]
+fn test_complement_within() {
+    let bitmap = Bitmap::with_bit_count(vec![0b1111_0101u8], 4);
+    let complement = bitmap.complement_within(8);
+    assert_eq!(complement.size(), 8);
+    assert_eq!(complement.iter().collect::<Vec<_>>(), [1, 3, 4, 5, 6, 7]);
+
+    let bitmap = Bitmap::new(vec![0x00FFu16, 0xFFFF]);
+    let complement = bitmap.complement_within(12);
+    assert_eq!(complement.size(), 12);
+    assert_eq!(complement.iter().collect::<Vec<_>>(), [8, 9, 10, 11]);
+}
+
+#[test]
+fn test_filter_bits() {
+    let mut bitmap = Bitmap::new(vec![0u16; 4]);
+    bitmap.set_bit_range(3..13);
+    bitmap.set_bit(40);
+    bitmap.set_bit(63);
+
+    let mut visited = Vec::new();
+    bitmap.filter_bits(|bit_index| {
+        visited.push(bit_index);
+        bit_index % 4 == 0
+    });
+
+    assert_eq!(visited, [3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 40, 63]);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [4, 8, 12, 40]);
+}
+
+#[test]
+fn test_longest_common_set_run() {
+    let mut lhs = Bitmap::new(vec![0u16; 3]);
+    lhs.set_bit_range(2..6);
+    lhs.set_bit_range(10..20);
+    lhs.set_bit_range(30..32);
+
+    let mut rhs = Bitmap::new(vec![0u16; 3]);
+    rhs.set_bit_range(4..11);
+    rhs.set_bit_range(17..25);
+    rhs.set_bit_range(29..40);
+
+    assert_eq!(lhs.longest_common_set_run(&rhs), Some((17, 3)));
+    assert_eq!(rhs.longest_common_set_run(&lhs), Some((17, 3)));
+
+    rhs.clear_bit(18);
+    assert_eq!(lhs.longest_common_set_run(&rhs), Some((4, 2)));
+
+    rhs.clear_bit_range(0..48);
+    assert_eq!(lhs.longest_common_set_run(&rhs), None);
+}
+
+#[test]
+fn test_clone() {
+    let mut bitmap: Bitmap<Vec<u64>, u64> = Bitmap::from_set_indices(70, &[1, 9, 63, 64]);
+    let snapshot = bitmap.clone();
+
+    bitmap.clear_bit(9);
+    bitmap.set_bit(2);
+
+    assert_eq!(snapshot.size(), 70);
+    assert_eq!(snapshot.iter().collect::<Vec<_>>(), [1, 9, 63, 64]);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [1, 2, 63, 64]);
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send<T: Send>() { }
+    fn assert_sync<T: Sync>() { }
+
+    assert_send::<Bitmap<Vec<u64>, u64>>();
+    assert_sync::<Bitmap<Vec<u64>, u64>>();
+    assert_send::<Bitmap<[u8; 4], u8>>();
+    assert_sync::<Bitmap<[u8; 4], u8>>();
+
+    let bitmap = std::sync::Arc::new(Bitmap::<Vec<u64>, u64>::from_set_indices(70, &[3, 65]));
+    let shared = bitmap.clone();
+    let set_bits = std::thread::spawn(move || shared.iter().collect::<Vec<_>>()).join().unwrap();
+    assert_eq!(set_bits, [3, 65]);
+}
+
+#[test]
+fn test_grow_to_fit() {
+    let mut bitmap = Bitmap::with_bit_count(vec![0b11110001u8], 5);
+
+    bitmap.grow_to_fit(3);
+    assert_eq!(bitmap.size(), 5);
+
+    bitmap.set_bit_growing(6);
+    assert_eq!(bitmap.size(), 8);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [0, 4, 6]);
+
+    bitmap.set_bit_growing(20);
+    assert_eq!(bitmap.size(), 24);
+    assert_eq!(bitmap.into_inner(), [0b01010001, 0, 0b00010000]);
+}
+
+#[test]
+fn test_shrink_to_fit() {
+    let mut bitmap: Bitmap<Vec<u16>, u16> = Bitmap::from_set_indices(70, &[3, 20]);
+    bitmap.set_bit(66);
+    bitmap.clear_bit(66);
+
+    bitmap.shrink_to_fit();
+    assert_eq!(bitmap.size(), 32);
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), [3, 20]);
+    assert_eq!(bitmap.bit_capacity(), 32);
+
+    let mut bitmap: Bitmap<Vec<u16>, u16> = Bitmap::from_set_indices(40, &[35]);
+    bitmap.shrink_to_fit();
+    assert_eq!(bitmap.size(), 40);
+
+    bitmap.clear_bit(35);
+    bitmap.shrink_to_fit();
+    assert_eq!(bitmap.size(), 0);
+    assert!(bitmap.into_inner().is_empty());
+}
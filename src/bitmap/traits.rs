@@ -1,66 +1,301 @@
 
 use super::Bitmap;
 
-use crate::store::BitStore;
+use crate::slice::{BitmapSliceIter, DEBUG_MAXIMUM_BIT_COUNT};
+use crate::store::{BitOrder, BitStore};
+#[cfg(feature = "alloc")]
+use crate::traits::BitmapOptsMut;
 
-use std::ops::{BitAndAssign, BitOrAssign, BitXorAssign};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
 
-impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, O: AsRef<[B]>> BitAndAssign<Bitmap<O, B>> for Bitmap<S, B> {
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 
-    fn bitand_assign(&mut self, rhs: Bitmap<O, B>) {
+impl<B: BitStore, S: AsRef<[B]> + FromIterator<B>, OS: AsRef<[B]>, O: BitOrder> BitAnd<Bitmap<OS, B, O>> for Bitmap<S, B, O> {
+
+    type Output = Bitmap<S, B, O>;
+
+    fn bitand(self, rhs: Bitmap<OS, B, O>) -> Self::Output {
+        &self & &rhs
+    }
+
+}
+
+///
+/// Computes the bitwise AND of two bitmaps into a new owned bitmap. Only the bits shared
+/// by both operands are combined, so the result has the length of the shorter operand.
+/// 
+impl<B: BitStore, S: AsRef<[B]> + FromIterator<B>, OS: AsRef<[B]>, O: BitOrder> BitAnd<&Bitmap<OS, B, O>> for &Bitmap<S, B, O> {
+
+    type Output = Bitmap<S, B, O>;
+
+    fn bitand(self, rhs: &Bitmap<OS, B, O>) -> Self::Output {
+        let bit_count = core::cmp::min(self.bit_count, rhs.bit_count);
+        let result_store = self.bitmap_store
+            .as_ref()
+            .iter()
+            .zip(rhs.bitmap_store.as_ref().iter())
+            .take(crate::polyfill::div_ceil(bit_count, B::BIT_COUNT))
+            .map(|(lhs, rhs)| *lhs & *rhs)
+            .collect();
+
+        Bitmap::with_order_and_bit_count(result_store, bit_count, O::default())
+    }
+
+}
+
+impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, OS: AsRef<[B]>, O: BitOrder> BitAndAssign<Bitmap<OS, B, O>> for Bitmap<S, B, O> {
+
+    fn bitand_assign(&mut self, rhs: Bitmap<OS, B, O>) {
         *self &= &rhs;
     }
 
 }
 
-impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, O: AsRef<[B]>> BitAndAssign<&Bitmap<O, B>> for Bitmap<S, B> {
+impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, OS: AsRef<[B]>, O: BitOrder> BitAndAssign<&Bitmap<OS, B, O>> for Bitmap<S, B, O> {
+
+    fn bitand_assign(&mut self, rhs: &Bitmap<OS, B, O>) {
+        self.combine_words(rhs, |dest, src| dest & src);
+    }
+
+}
+
+impl<B: BitStore, S: AsRef<[B]> + FromIterator<B>, OS: AsRef<[B]>, O: BitOrder> BitOr<Bitmap<OS, B, O>> for Bitmap<S, B, O> {
+
+    type Output = Bitmap<S, B, O>;
+
+    fn bitor(self, rhs: Bitmap<OS, B, O>) -> Self::Output {
+        &self | &rhs
+    }
+
+}
+
+///
+/// Computes the bitwise OR of two bitmaps into a new owned bitmap. Only the bits shared
+/// by both operands are combined, so the result has the length of the shorter operand.
+/// 
+impl<B: BitStore, S: AsRef<[B]> + FromIterator<B>, OS: AsRef<[B]>, O: BitOrder> BitOr<&Bitmap<OS, B, O>> for &Bitmap<S, B, O> {
+
+    type Output = Bitmap<S, B, O>;
 
-    fn bitand_assign(&mut self, rhs: &Bitmap<O, B>) {
-        self.bitmap_store
-            .as_mut()
-            .iter_mut()
+    fn bitor(self, rhs: &Bitmap<OS, B, O>) -> Self::Output {
+        let bit_count = core::cmp::min(self.bit_count, rhs.bit_count);
+        let result_store = self.bitmap_store
+            .as_ref()
+            .iter()
             .zip(rhs.bitmap_store.as_ref().iter())
-            .for_each(|(dest, src)| *dest &= *src);
+            .take(crate::polyfill::div_ceil(bit_count, B::BIT_COUNT))
+            .map(|(lhs, rhs)| *lhs | *rhs)
+            .collect();
+
+        Bitmap::with_order_and_bit_count(result_store, bit_count, O::default())
     }
 
 }
 
-impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, O: AsRef<[B]>> BitOrAssign<Bitmap<O, B>> for Bitmap<S, B> {
+impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, OS: AsRef<[B]>, O: BitOrder> BitOrAssign<Bitmap<OS, B, O>> for Bitmap<S, B, O> {
 
-    fn bitor_assign(&mut self, rhs: Bitmap<O, B>) {
+    fn bitor_assign(&mut self, rhs: Bitmap<OS, B, O>) {
         *self |= &rhs;
     }
 
 }
 
-impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, O: AsRef<[B]>> BitOrAssign<&Bitmap<O, B>> for Bitmap<S, B> {
+impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, OS: AsRef<[B]>, O: BitOrder> BitOrAssign<&Bitmap<OS, B, O>> for Bitmap<S, B, O> {
+
+    fn bitor_assign(&mut self, rhs: &Bitmap<OS, B, O>) {
+        self.combine_words(rhs, |dest, src| dest | src);
+    }
+
+}
+
+impl<B: BitStore, S: AsRef<[B]> + FromIterator<B>, OS: AsRef<[B]>, O: BitOrder> BitXor<Bitmap<OS, B, O>> for Bitmap<S, B, O> {
+
+    type Output = Bitmap<S, B, O>;
+
+    fn bitxor(self, rhs: Bitmap<OS, B, O>) -> Self::Output {
+        &self ^ &rhs
+    }
+
+}
+
+///
+/// Computes the bitwise XOR of two bitmaps into a new owned bitmap. Only the bits shared
+/// by both operands are combined, so the result has the length of the shorter operand.
+/// 
+impl<B: BitStore, S: AsRef<[B]> + FromIterator<B>, OS: AsRef<[B]>, O: BitOrder> BitXor<&Bitmap<OS, B, O>> for &Bitmap<S, B, O> {
 
-    fn bitor_assign(&mut self, rhs: &Bitmap<O, B>) {
-        self.bitmap_store
-            .as_mut()
-            .iter_mut()
+    type Output = Bitmap<S, B, O>;
+
+    fn bitxor(self, rhs: &Bitmap<OS, B, O>) -> Self::Output {
+        let bit_count = core::cmp::min(self.bit_count, rhs.bit_count);
+        let result_store = self.bitmap_store
+            .as_ref()
+            .iter()
             .zip(rhs.bitmap_store.as_ref().iter())
-            .for_each(|(dest, src)| *dest |= *src);
+            .take(crate::polyfill::div_ceil(bit_count, B::BIT_COUNT))
+            .map(|(lhs, rhs)| *lhs ^ *rhs)
+            .collect();
+
+        Bitmap::with_order_and_bit_count(result_store, bit_count, O::default())
     }
 
 }
 
-impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, O: AsRef<[B]>> BitXorAssign<Bitmap<O, B>> for Bitmap<S, B> {
+impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, OS: AsRef<[B]>, O: BitOrder> BitXorAssign<Bitmap<OS, B, O>> for Bitmap<S, B, O> {
 
-    fn bitxor_assign(&mut self, rhs: Bitmap<O, B>) {
+    fn bitxor_assign(&mut self, rhs: Bitmap<OS, B, O>) {
         *self ^= &rhs;
     }
 
 }
 
-impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, O: AsRef<[B]>> BitXorAssign<&Bitmap<O, B>> for Bitmap<S, B> {
+impl<B: BitStore, S: AsRef<[B]> + AsMut<[B]>, OS: AsRef<[B]>, O: BitOrder> BitXorAssign<&Bitmap<OS, B, O>> for Bitmap<S, B, O> {
 
-    fn bitxor_assign(&mut self, rhs: &Bitmap<O, B>) {
-        self.bitmap_store
-            .as_mut()
-            .iter_mut()
-            .zip(rhs.bitmap_store.as_ref().iter())
-            .for_each(|(dest, src)| *dest ^= *src);
+    fn bitxor_assign(&mut self, rhs: &Bitmap<OS, B, O>) {
+        self.combine_words(rhs, |dest, src| dest ^ src);
+    }
+
+}
+
+impl<S: Clone, B, O> Clone for Bitmap<S, B, O> {
+
+    fn clone(&self) -> Self {
+        Bitmap { _bs: PhantomData, bit_count: self.bit_count, bitmap_store: self.bitmap_store.clone() }
+    }
+
+}
+
+impl<B: BitStore, S: AsRef<[B]> + ?Sized, O: BitOrder> fmt::Debug for Bitmap<S, B, O> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bitmap {{ size: {}, bits: ", self.bit_count)?;
+        self.as_slice().write_bits(f, 8, DEBUG_MAXIMUM_BIT_COUNT)?;
+        f.write_str(" }")
+    }
+
+}
+
+///
+/// Compares two bitmaps by their logical bits. Bitmaps are equal if they have the same size
+/// and the same bits set, regardless of any padding bits or their backing word type.
+/// 
+impl<B: BitStore, S: AsRef<[B]> + ?Sized, O: BitOrder, OB: BitStore, OS: AsRef<[OB]> + ?Sized, OO: BitOrder> PartialEq<Bitmap<OS, OB, OO>> for Bitmap<S, B, O> {
+
+    fn eq(&self, other: &Bitmap<OS, OB, OO>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+
+}
+
+impl<B: BitStore, S: AsRef<[B]> + ?Sized, O: BitOrder> Eq for Bitmap<S, B, O> { }
+
+///
+/// Hashes the logical bits of this bitmap, consistent with its `PartialEq` implementation.
+/// 
+impl<B: BitStore, S: AsRef<[B]> + ?Sized, O: BitOrder> Hash for Bitmap<S, B, O> {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+
+}
+
+///
+/// Formats this bitmap as a string of `0` and `1` characters starting from bit 0.
+/// 
+impl<B: BitStore, S: AsRef<[B]> + ?Sized, O: BitOrder> fmt::Display for Bitmap<S, B, O> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_slice(), f)
+    }
+
+}
+
+impl<'a, B: BitStore, S: AsRef<[B]> + ?Sized, O: BitOrder> IntoIterator for &'a Bitmap<S, B, O> {
+
+    type Item = usize;
+    type IntoIter = BitmapSliceIter<'a, B, O>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+
+}
+
+///
+/// Builds a bitmap from a sequence of bits, where the first item is bit 0. The resulting
+/// bitmap has one bit per item.
+/// 
+#[cfg(feature = "alloc")]
+impl<B: BitStore, O: BitOrder> FromIterator<bool> for Bitmap<Vec<B>, B, O> {
+
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bitmap_store = Vec::new();
+        let mut bit_count = 0;
+        for bit_value in iter {
+            let bit_offset = bit_count % B::BIT_COUNT;
+            if bit_offset == 0 {
+                bitmap_store.push(B::ZERO);
+            }
+
+            if bit_value {
+                let last_slot = bitmap_store.len() - 1;
+                bitmap_store[last_slot] |= O::create_bit_mask::<B>(bit_offset);
+            }
+
+            bit_count += 1;
+        }
+
+        Bitmap::with_order_and_bit_count(bitmap_store, bit_count, O::default())
+    }
+
+}
+
+///
+/// Builds a bitmap from a sequence of set bit indices. The resulting bitmap covers every
+/// bit in the slots needed to hold the largest index, and duplicate indices are ignored.
+/// 
+#[cfg(feature = "alloc")]
+impl<B: BitStore, O: BitOrder> FromIterator<usize> for Bitmap<Vec<B>, B, O> {
+
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let set_indices: Vec<usize> = iter.into_iter().collect();
+        let slot_count = set_indices
+            .iter()
+            .max()
+            .map(|max_index| crate::polyfill::div_ceil(max_index + 1, B::BIT_COUNT))
+            .unwrap_or(0);
+
+        let mut bitmap = Bitmap::with_order(vec![B::ZERO; slot_count], O::default());
+        for set_index in set_indices {
+            bitmap.set_bit(set_index);
+        }
+
+        bitmap
+    }
+
+}
+
+///
+/// Sets the bit at every index in the sequence. Indices past the end of the bitmap grow
+/// the backing store by the slots needed to hold them, leaving any intervening bits clear.
+/// 
+#[cfg(feature = "alloc")]
+impl<B: BitStore, O: BitOrder> Extend<usize> for Bitmap<Vec<B>, B, O> {
+
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for set_index in iter {
+            if set_index >= self.bit_count {
+                let slot_count = crate::polyfill::div_ceil(set_index + 1, B::BIT_COUNT);
+                self.resize_bits(slot_count * B::BIT_COUNT, false);
+            }
+
+            self.set_bit(set_index);
+        }
     }
 
 }
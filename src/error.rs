@@ -0,0 +1,152 @@
+
+use core::fmt;
+
+///
+/// The error returned when a bitmap fails a consistency check.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BitmapError {
+
+    ///
+    /// A bit at or beyond the declared `capacity` is set. `bit_index` is the zero based
+    /// index of the first such bit.
+    /// 
+    CapacityExceeded { capacity: usize, bit_index: usize },
+
+    ///
+    /// The requested logical length `bit_count` is larger than the `capacity` in bits of the
+    /// backing storage.
+    /// 
+    LengthExceedsCapacity { bit_count: usize, capacity: usize },
+
+    ///
+    /// The two operands of an operation that requires equal length bitmaps have different
+    /// logical lengths.
+    /// 
+    LengthMismatch { lhs_bit_count: usize, rhs_bit_count: usize }
+
+}
+
+impl fmt::Display for BitmapError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitmapError::CapacityExceeded { capacity, bit_index } => {
+                write!(f, "Bit {} is set beyond the bitmap capacity of {} bits", bit_index, capacity)
+            },
+            BitmapError::LengthExceedsCapacity { bit_count, capacity } => {
+                write!(f, "Bitmap length {} exceeds the buffer capacity of {} bits", bit_count, capacity)
+            },
+            BitmapError::LengthMismatch { lhs_bit_count, rhs_bit_count } => {
+                write!(f, "Bitmap lengths do not match ({} != {})", lhs_bit_count, rhs_bit_count)
+            }
+        }
+    }
+
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitmapError { }
+
+///
+/// The error returned when parsing a bitmap from its textual representation fails.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseError {
+
+    ///
+    /// The input contained a character other than `'0'`, `'1'`, whitespace or `'_'`.
+    /// `position` is the zero based byte offset of the character within the input.
+    /// 
+    InvalidCharacter { position: usize, character: char }
+
+}
+
+impl fmt::Display for ParseError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidCharacter { position, character } => {
+                write!(f, "Invalid character {:?} at position {} in bit string", character, position)
+            }
+        }
+    }
+
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError { }
+
+///
+/// The error returned when decoding a bitmap from its delta varint encoding fails.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+
+    ///
+    /// The input ended in the middle of a varint. `byte_count` is the length of the input.
+    /// 
+    TruncatedVarint { byte_count: usize },
+
+    ///
+    /// The varint ending at the zero based byte offset `position` does not fit in a usize.
+    /// 
+    VarintOverflow { position: usize },
+
+    ///
+    /// The varint ending at the zero based byte offset `position` decodes to `bit_index`,
+    /// which is not within the `bit_count` bits of the bitmap.
+    /// 
+    BitIndexOutOfRange { bit_index: usize, bit_count: usize, position: usize }
+
+}
+
+impl fmt::Display for DecodeError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::TruncatedVarint { byte_count } => {
+                write!(f, "Truncated delta varint at end of buffer of {} bytes", byte_count)
+            },
+            DecodeError::VarintOverflow { position } => {
+                write!(f, "Delta varint overflows a usize at byte {}", position)
+            },
+            DecodeError::BitIndexOutOfRange { bit_index, bit_count, position } => {
+                write!(f, "Invalid bit index ({} >= {}) decoded at byte {}", bit_index, bit_count, position)
+            }
+        }
+    }
+
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError { }
+
+///
+/// The error returned when a bit index is past the end of a bitmap.
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutOfBoundsError {
+
+    ///
+    /// The offending bit index.
+    /// 
+    pub bit_index: usize,
+
+    ///
+    /// The size in bits of the bitmap that was accessed.
+    /// 
+    pub size: usize
+
+}
+
+impl fmt::Display for OutOfBoundsError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bit index {} is out of bounds for bitmap of size {}", self.bit_index, self.size)
+    }
+
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBoundsError { }
@@ -1,5 +1,10 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub mod bitmap;
+pub mod error;
 pub mod slice;
 pub mod store;
 pub mod traits;
@@ -7,20 +12,48 @@ mod polyfill;
 
 pub mod prelude {
 
-    pub use crate::bitmap::Bitmap;
+    pub use crate::bitmap::{
+        AtomicBitmap,
+        Bitmap
+    };
+    #[cfg(feature = "alloc")]
+    pub use crate::bitmap::JournaledBitmap;
     pub use crate::slice::{
+        BitRef,
+        BitmapChunks,
+        BitmapChunksMut,
+        BitmapClearIter,
+        BitmapClearRangeIter,
         BitmapSlice,
         BitmapSliceImpl,
         BitmapSliceIter,
+        BitmapSliceIterMut,
         BitmapSliceMut,
-        BitmapSliceRangeIter
+        BitmapSliceRangeIter,
+        BitmapSliceRevIter,
+        BitmapSliceWordIter,
+        BitmapWindows
     };
+    #[cfg(feature = "alloc")]
+    pub use crate::slice::ChainedBitmap;
 
-    pub use crate::store::BitStore;
+    pub use crate::store::{
+        AtomicBitStore,
+        BitOrder,
+        BitStore,
+        ByteArray,
+        Lsb0,
+        Msb0
+    };
     pub use crate::traits::{
         BitmapOpts,
         BitmapOptsMut
     };
+    #[cfg(feature = "alloc")]
+    pub use crate::traits::{
+        BitmapOptsAlloc,
+        BitmapOptsMutAlloc
+    };
     
     pub use crate::polyfill::{
         Const,
@@ -1,22 +1,40 @@
 
 mod api;
+#[cfg(feature = "alloc")]
+mod chained;
 mod iter;
 mod internal;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod test;
 
 use crate::polyfill::{Const, Mut};
+use crate::store::Lsb0;
 
+pub(crate) use self::api::DEBUG_MAXIMUM_BIT_COUNT;
 pub use self::api::BitmapSliceImpl;
-pub use self::iter::{BitmapSliceIter, BitmapSliceRangeIter};
+#[cfg(feature = "alloc")]
+pub use self::chained::ChainedBitmap;
+pub use self::iter::{
+    BitRef,
+    BitmapChunks,
+    BitmapChunksMut,
+    BitmapClearIter,
+    BitmapClearRangeIter,
+    BitmapSliceIter,
+    BitmapSliceIterMut,
+    BitmapSliceRangeIter,
+    BitmapSliceRevIter,
+    BitmapSliceWordIter,
+    BitmapWindows
+};
 
 ///
 /// Alias for a non-mutable [slice::BitmapSliceImpl](BitmapSliceImpl).
 /// 
-pub type BitmapSlice<'a, B = usize> = BitmapSliceImpl<'a, B, Const>;
+pub type BitmapSlice<'a, B = usize, O = Lsb0> = BitmapSliceImpl<'a, B, Const, O>;
 
 ///
 /// Alias for a mutable [slice::BitmapSliceImpl](BitmapSliceImpl).
 /// 
-pub type BitmapSliceMut<'a, B = usize> = BitmapSliceImpl<'a, B, Mut>;
+pub type BitmapSliceMut<'a, B = usize, O = Lsb0> = BitmapSliceImpl<'a, B, Mut, O>;
@@ -1,34 +1,125 @@
 
-use crate::store::BitStore;
+use crate::store::{BitOrder, BitStore, Lsb0};
 use crate::traits::{BitmapOpts, BitmapOptsMut};
 use crate::polyfill::{Const, Mut, Mutability};
 
-use super::{BitmapSliceIter, BitmapSliceRangeIter};
+use super::{
+    BitmapChunks,
+    BitmapChunksMut,
+    BitmapClearIter,
+    BitmapClearRangeIter,
+    BitmapSlice,
+    BitmapSliceIter,
+    BitmapSliceIterMut,
+    BitmapSliceRangeIter,
+    BitmapSliceRevIter,
+    BitmapSliceWordIter,
+    BitmapWindows
+};
 use super::internal::BitmapSliceOperation;
 
-use std::marker::PhantomData;
-use std::ops::Range;
-use std::ptr::NonNull;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ptr::NonNull;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 
 ///
 /// Implements a bitmap slice over a subslice of a bitmap. A bitmap slice can be
 /// mutable, if the provided storage is mutable and can be split or shrunk as
 /// needed. A bitmap slice does not support owning the underlying storage.
 /// 
-pub struct BitmapSliceImpl<'a, B: BitStore, M: Mutability> {
+pub struct BitmapSliceImpl<'a, B: BitStore, M: Mutability, O: BitOrder = Lsb0> {
     pub(super) buffer_address: NonNull<B>,
     pub(super) bit_count: usize,
     pub(super) first_bit_offset: u8,
     pub(super) _lt: PhantomData<(&'a [B], &'a mut [B])>,
-    pub(super) _mut: PhantomData<M>
+    pub(super) _mut: PhantomData<M>,
+    pub(super) _order: PhantomData<O>
 }
 
-impl<'a, B: BitStore> Copy for BitmapSliceImpl<'a, B, Const> { }
-impl<'a, B: BitStore> Clone for BitmapSliceImpl<'a, B, Const> {
+impl<'a, B: BitStore, O: BitOrder> Copy for BitmapSliceImpl<'a, B, Const, O> { }
+impl<'a, B: BitStore, O: BitOrder> Clone for BitmapSliceImpl<'a, B, Const, O> {
 
     fn clone(&self) -> Self {
-        unsafe {
-            Self::from_raw_parts(self.buffer_address, self.first_bit_offset, self.bit_count)
+        *self
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> IntoIterator for BitmapSliceImpl<'a, B, Const, O> {
+
+    type Item = usize;
+    type IntoIter = BitmapSliceIter<'a, B, O>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BitmapSliceIter::new(self)
+    }
+
+}
+
+impl<'s, 'a, B: BitStore, M: Mutability, O: BitOrder> IntoIterator for &'s BitmapSliceImpl<'a, B, M, O> {
+
+    type Item = usize;
+    type IntoIter = BitmapSliceIter<'s, B, O>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+
+}
+
+///
+/// The maximum number of bits written by the `Debug` implementations of bitmaps and
+/// bitmap slices before the output is truncated.
+/// 
+pub(crate) const DEBUG_MAXIMUM_BIT_COUNT: usize = 256;
+
+impl<'a, B: BitStore, M: Mutability, O: BitOrder> fmt::Debug for BitmapSliceImpl<'a, B, M, O> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BitmapSlice {{ size: {}, bits: ", self.bit_count)?;
+        self.write_bits(f, 8, DEBUG_MAXIMUM_BIT_COUNT)?;
+        f.write_str(" }")
+    }
+
+}
+
+impl<'a, B: BitStore, M: Mutability, O: BitOrder> fmt::Display for BitmapSliceImpl<'a, B, M, O> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_bits(f, 0, usize::MAX)
+    }
+
+}
+
+///
+/// Compares two bitmap slices by their logical bits. Slices are equal if they have the same
+/// size and the same bits set, regardless of their alignment or backing word type.
+/// 
+impl<'a, 'b, B: BitStore, M: Mutability, O: BitOrder, OB: BitStore, OM: Mutability, OO: BitOrder> PartialEq<BitmapSliceImpl<'b, OB, OM, OO>> for BitmapSliceImpl<'a, B, M, O> {
+
+    fn eq(&self, other: &BitmapSliceImpl<'b, OB, OM, OO>) -> bool {
+        (self.size() == other.size()) && self.range_iter().eq(other.range_iter())
+    }
+
+}
+
+impl<'a, B: BitStore, M: Mutability, O: BitOrder> Eq for BitmapSliceImpl<'a, B, M, O> { }
+
+///
+/// Hashes the logical bits of this slice, consistent with its `PartialEq` implementation.
+/// 
+impl<'a, B: BitStore, M: Mutability, O: BitOrder> Hash for BitmapSliceImpl<'a, B, M, O> {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.size());
+        for (range_start, range_count) in self.range_iter() {
+            state.write_usize(range_start);
+            state.write_usize(range_count);
         }
     }
 
@@ -40,16 +131,45 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Const> {
     /// Creates a new non-mutable slice over the provided storage covering the
     /// provided range.
     /// 
-    pub fn new(mut buffer: &'a [B], bit_range: Range<usize>) -> Self {
+    pub fn new(buffer: &'a [B], bit_range: Range<usize>) -> Self {
+        Self::with_order(buffer, bit_range, Lsb0)
+    }
+
+    ///
+    /// Creates a new non-mutable slice over the provided storage. `first_bit_offset` must be
+    /// less than `B::BIT_COUNT` and `bit_count` must be less than or equal to
+    /// `buffer.len() * B::BIT_COUNT - first_bit_offset`. These conditions are not checked
+    /// and hence this routine is marked as unsafe.
+    /// 
+    /// # Safety
+    /// 
+    /// The caller must ensure that `first_bit_offset < B::BIT_COUNT` and that
+    /// `first_bit_offset + bit_count <= buffer.len() * B::BIT_COUNT`.
+    /// 
+    pub unsafe fn new_unchecked(buffer: &'a [B], first_bit_offset: u8, bit_count: usize) -> Self {
+        let buffer_address = NonNull::new_unchecked(buffer.as_ptr() as *mut _);
+
+        debug_assert!((first_bit_offset as usize) < B::BIT_COUNT);
+
+        Self::from_raw_parts(buffer_address, first_bit_offset, bit_count)
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceImpl<'a, B, Const, O> {
+
+    ///
+    /// Creates a new non-mutable slice over the provided storage covering the
+    /// provided range, numbering the bits of each backing element in the order `O`.
+    /// 
+    pub fn with_order(mut buffer: &'a [B], bit_range: Range<usize>, _order: O) -> Self {
         if bit_range.start > bit_range.end {
             panic!("Invalid bit range start ({}) > end ({})", bit_range.start, bit_range.end);
 
         } else {
             let starting_slot = bit_range.start / B::BIT_COUNT;
             let ending_slot = crate::polyfill::div_ceil(bit_range.end, B::BIT_COUNT);
-            if (starting_slot >= bit_range.len()) ||
-               (ending_slot > bit_range.len()) {
-
+            if ending_slot > buffer.len() {
                 panic!("Invalid bit range [{}:{}] for buffer of size {}",
                        starting_slot,
                        ending_slot,
@@ -66,14 +186,31 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Const> {
         }
     }
 
+}
+
+impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
+
     ///
-    /// Creates a new non-mutable slice over the provided storage. `first_bit_offset` must be
+    /// Creates a new mutable slice over the provided storage covering the
+    /// provided range.
+    /// 
+    pub fn new(buffer: &'a mut [B], bit_range: Range<usize>) -> Self {
+        Self::with_order(buffer, bit_range, Lsb0)
+    }
+
+    ///
+    /// Creates a new mutable slice over the provided storage. `first_bit_offset` must be
     /// less than `B::BIT_COUNT` and `bit_count` must be less than or equal to
     /// `buffer.len() * B::BIT_COUNT - first_bit_offset`. These conditions are not checked
     /// and hence this routine is marked as unsafe.
     /// 
-    pub unsafe fn new_unchecked(buffer: &'a [B], first_bit_offset: u8, bit_count: usize) -> Self {
-        let buffer_address = NonNull::new_unchecked(buffer.as_ptr() as *mut _);
+    /// # Safety
+    /// 
+    /// The caller must ensure that `first_bit_offset < B::BIT_COUNT` and that
+    /// `first_bit_offset + bit_count <= buffer.len() * B::BIT_COUNT`.
+    /// 
+    pub unsafe fn new_unchecked(buffer: &'a mut [B], first_bit_offset: u8, bit_count: usize) -> Self {
+        let buffer_address = NonNull::new_unchecked(buffer.as_mut_ptr());
 
         debug_assert!((first_bit_offset as usize) < B::BIT_COUNT);
 
@@ -82,22 +219,20 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Const> {
 
 }
 
-impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceImpl<'a, B, Mut, O> {
 
     ///
     /// Creates a new mutable slice over the provided storage covering the
-    /// provided range.
+    /// provided range, numbering the bits of each backing element in the order `O`.
     /// 
-    pub fn new(mut buffer: &'a mut [B], bit_range: Range<usize>) -> Self {
+    pub fn with_order(mut buffer: &'a mut [B], bit_range: Range<usize>, _order: O) -> Self {
         if bit_range.start > bit_range.end {
             panic!("Invalid bit range start ({}) > end ({})", bit_range.start, bit_range.end);
 
         } else {
             let starting_slot = bit_range.start / B::BIT_COUNT;
             let ending_slot = crate::polyfill::div_ceil(bit_range.end, B::BIT_COUNT);
-            if (starting_slot >= bit_range.len()) ||
-               (ending_slot > bit_range.len()) {
-
+            if ending_slot > buffer.len() {
                 panic!("Invalid bit range [{}:{}] for buffer of size {}",
                        starting_slot,
                        ending_slot,
@@ -114,29 +249,52 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
         }
     }
 
+}
+
+impl<'a, B: BitStore, M: Mutability, O: BitOrder> BitmapSliceImpl<'a, B, M, O> {
+
     ///
-    /// Creates a new mutable slice over the provided storage. `first_bit_offset` must be
-    /// less than `B::BIT_COUNT` and `bit_count` must be less than or equal to
-    /// `buffer.len() * B::BIT_COUNT - first_bit_offset`. These conditions are not checked
-    /// and hence this routine is marked as unsafe.
+    /// Creates a new bitmap slice from its raw parts: the address of the first backing
+    /// word, the offset of the first bit within that word and the number of bits covered.
+    /// This is the inverse of [as_raw_parts](Self::as_raw_parts).
     /// 
-    pub unsafe fn new_unchecked(buffer: &'a mut [B], first_bit_offset: u8, bit_count: usize) -> Self {
-        let buffer_address = NonNull::new_unchecked(buffer.as_mut_ptr());
-
+    /// # Safety
+    /// 
+    /// The caller must ensure that:
+    /// 
+    /// - `first_bit_offset < B::BIT_COUNT`.
+    /// - `buffer_address` points to `div_ceil(first_bit_offset + bit_count, B::BIT_COUNT)`
+    ///   initialized words that stay valid for the lifetime `'a`.
+    /// - For a non-mutable slice, those words are not written through any other pointer for
+    ///   the lifetime `'a`. For a mutable slice, those words are not accessed through any
+    ///   other pointer for the lifetime `'a`, so no two mutable slices alias each other.
+    /// 
+    pub unsafe fn from_raw_parts(buffer_address: NonNull<B>, first_bit_offset: u8, bit_count: usize) -> Self {
         debug_assert!((first_bit_offset as usize) < B::BIT_COUNT);
 
-        Self::from_raw_parts(buffer_address, first_bit_offset, bit_count)
+        BitmapSliceImpl {
+            buffer_address,
+            bit_count,
+            first_bit_offset,
+            _lt: PhantomData,
+            _mut: PhantomData,
+            _order: PhantomData
+        }
     }
 
-}
-
-impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
+    ///
+    /// This routine returns the raw parts of this slice: the address of the first backing
+    /// word, the offset of the first bit within that word and the number of bits covered.
+    /// 
+    pub fn as_raw_parts(&self) -> (NonNull<B>, u8, usize) {
+        (self.buffer_address, self.first_bit_offset, self.bit_count)
+    }
 
     ///
     /// Temporarily downgrades this potentially mutable slice into a non-mutable
     /// slice over the same range of bits.
     /// 
-    pub fn as_const(&self) -> BitmapSliceImpl<B, Const> {
+    pub fn as_const(&self) -> BitmapSliceImpl<'_, B, Const, O> {
         unsafe {
             BitmapSliceImpl::from_raw_parts(self.buffer_address, self.first_bit_offset, self.bit_count)
         }
@@ -145,24 +303,315 @@ impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
     ///
     /// Returns an iterator over all set bits in this slice.
     /// 
-    pub fn iter(&self) -> BitmapSliceIter<B> {
+    pub fn iter(&self) -> BitmapSliceIter<'_, B, O> {
         BitmapSliceIter::new(self.as_const())
     }
 
+    ///
+    /// Returns an iterator over all set bits in this slice in descending order.
+    /// 
+    pub fn iter_rev(&self) -> BitmapSliceRevIter<'_, B, O> {
+        BitmapSliceRevIter::new(self.as_const())
+    }
+
     ///
     /// Returns an iterator over all ranges of set bits in this slice.
     /// 
-    pub fn range_iter(&self) -> BitmapSliceRangeIter<B> {
+    pub fn range_iter(&self) -> BitmapSliceRangeIter<'_, B, O> {
         BitmapSliceRangeIter::new(self.as_const())
     }
 
+    ///
+    /// Returns an iterator over all clear bits in this slice.
+    /// 
+    pub fn clear_iter(&self) -> BitmapClearIter<'_, B, O> {
+        BitmapClearIter::new(self.as_const())
+    }
+
+    ///
+    /// Returns an iterator over all ranges of clear bits in this slice.
+    /// 
+    pub fn clear_range_iter(&self) -> BitmapClearRangeIter<'_, B, O> {
+        BitmapClearRangeIter::new(self.as_const())
+    }
+
+    ///
+    /// Returns an iterator over every backing word covered by this slice, including
+    /// words without any set bits. Bits in the first and last words that fall outside
+    /// of this slice are masked off.
+    /// 
+    pub fn word_iter_all(&self) -> BitmapSliceWordIter<'_, B, O> {
+        BitmapSliceWordIter::new(self.as_const())
+    }
+
+    ///
+    /// Returns an iterator over consecutive subslices of this slice, each `chunk_bits` bits
+    /// long. The last subslice is shorter if the size of this slice is not a multiple of
+    /// `chunk_bits`. Bit 0 of the k-th subslice is bit `k * chunk_bits` of this slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `chunk_bits` is zero.
+    /// 
+    pub fn chunks(&self, chunk_bits: usize) -> BitmapChunks<'_, B, O> {
+        if chunk_bits == 0 {
+            panic!("Invalid chunk size ({})", chunk_bits);
+        }
+
+        BitmapChunks::new(self.as_const(), chunk_bits)
+    }
+
+    ///
+    /// Returns a lazy iterator over consecutive blocks of `block_bits` bits, yielding the
+    /// start of each block and the number of set bits within it. The last block is shorter
+    /// if the size of this slice is not a multiple of `block_bits`. Each count is computed one
+    /// word at a time.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `block_bits` is zero.
+    /// 
+    pub fn block_popcounts(&self, block_bits: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        if block_bits == 0 {
+            panic!("Invalid block size ({})", block_bits);
+        }
+
+        let slice = self.as_const();
+        (0..slice.size())
+            .step_by(block_bits)
+            .map(move |block_start| {
+                let block_end = core::cmp::min(block_start + block_bits, slice.size());
+                (block_start, slice.count_ones_in_range(block_start..block_end))
+            })
+    }
+
+    ///
+    /// Returns an iterator over every subslice of this slice that is `window_bits` bits
+    /// long, advancing one bit at a time. If `window_bits` is larger than the size of this
+    /// slice, the iterator yields nothing.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `window_bits` is zero.
+    /// 
+    pub fn windows(&self, window_bits: usize) -> BitmapWindows<'_, B, O> {
+        if window_bits == 0 {
+            panic!("Invalid window size ({})", window_bits);
+        }
+
+        BitmapWindows::new(self.as_const(), window_bits)
+    }
+
+    ///
+    /// This routine returns `true` if the bit at the provided index is set, otherwise returns
+    /// false, without checking that the index is within this slice.
+    /// 
+    /// # Safety
+    /// 
+    /// The caller must ensure that `bit_index < self.size()`. Passing an index past the end
+    /// of this slice reads outside of the backing buffer and is undefined behavior.
+    /// 
+    #[inline(always)]
+    pub unsafe fn get_bit_unchecked(&self, bit_index: usize) -> bool {
+        let (slot, offset) = self.translate_bit_index_unchecked(bit_index);
+        let slot_contents = self.buffer_address.as_ptr().add(slot).read();
+
+        (slot_contents & O::create_bit_mask::<B>(offset)) != B::ZERO
+    }
+
+    ///
+    /// This routine returns the index, relative to the first word covered by this slice, of
+    /// the highest backing word that contains a set bit within this slice. The words are
+    /// scanned from the top down. If this slice does not contain any set bits, None is returned.
+    /// 
+    pub fn highest_active_word(&self) -> Option<usize> {
+        (0..self.slot_count())
+            .rev()
+            .find(|slot| self.read_masked_slot(*slot).leading_zeros() < B::BIT_COUNT)
+    }
+
+    ///
+    /// This routine returns the range of backing word indices, relative to the first word
+    /// covered by this slice, from the word holding the first set bit to the word holding the
+    /// last set bit (inclusive). If this slice does not contain any set bits, None is returned.
+    /// 
+    pub fn active_word_range(&self) -> Option<Range<usize>> {
+        let first_set_bit = self.find_first_set()?;
+        let last_set_bit = self.find_last_set()?;
+        let first_bit_offset = self.first_bit_offset as usize;
+
+        Some(((first_set_bit + first_bit_offset) / B::BIT_COUNT)..((last_set_bit + first_bit_offset) / B::BIT_COUNT + 1))
+    }
+
+    ///
+    /// This routine returns the backing words that lie entirely within this slice, excluding
+    /// any partially covered first and last words. The returned tuple also contains the number
+    /// of leading and trailing bits of this slice that fall outside of the aligned words. If
+    /// no backing word is fully covered by this slice, None is returned.
+    /// 
+    pub fn aligned_interior(&self) -> Option<(&[B], usize, usize)> {
+        let first_bit_offset = self.first_bit_offset as usize;
+        let first_slot = crate::polyfill::div_ceil(first_bit_offset, B::BIT_COUNT);
+        let end_slot = (first_bit_offset + self.bit_count) / B::BIT_COUNT;
+        if first_slot >= end_slot {
+            return None;
+        }
+
+        let leading_bits = first_slot * B::BIT_COUNT - first_bit_offset;
+        let trailing_bits = (first_bit_offset + self.bit_count) % B::BIT_COUNT;
+        let words = unsafe {
+            core::slice::from_raw_parts(self.buffer_address.as_ptr().add(first_slot), end_slot - first_slot)
+        };
+
+        Some((words, leading_bits, trailing_bits))
+    }
+
+    ///
+    /// This routine returns every backing word spanned by this slice. The first and last
+    /// words may contain bits outside of this slice, which are returned unmasked.
+    /// 
+    /// # Safety
+    /// 
+    /// Slices split with [split_at_mut](Self::split_at_mut) share the word holding the split
+    /// point. The caller must ensure that no other slice writes to the first or last word of
+    /// this slice while the returned words are alive. Slices created directly over a shared
+    /// buffer with [new](BitmapSliceImpl::new) always satisfy this requirement.
+    /// 
+    pub unsafe fn as_word_slice(&self) -> &[B] {
+        core::slice::from_raw_parts(self.buffer_address.as_ptr(), self.slot_count())
+    }
+
+    ///
+    /// This routine returns a tuple containing the number of bits that must be set and the
+    /// number of bits that must be cleared to transform this slice into `target`. Both counts
+    /// are computed in a single pass, one word at a time. The shorter slice is treated as if it
+    /// were extended with clear bits to the length of the longer slice.
+    /// 
+    pub fn change_counts<OM: Mutability>(&self, target: &BitmapSliceImpl<'_, B, OM, O>) -> (usize, usize) {
+        self.chunk_pairs(target)
+            .fold((0, 0), |(set_count, clear_count), (current, target)| {
+                (set_count + (target & !current).count_ones(), clear_count + (current & !target).count_ones())
+            })
+    }
+
+    ///
+    /// This routine returns the number of bit positions that differ between this slice and
+    /// `other`, computed one word at a time as the count of set bits in `self ^ other`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if this slice and `other` have different sizes.
+    /// 
+    pub fn hamming_distance<OM: Mutability>(&self, other: &BitmapSliceImpl<'_, B, OM, O>) -> usize {
+        if self.bit_count != other.bit_count {
+            panic!("Bitmap sizes do not match ({} != {})", self.bit_count, other.bit_count);
+        }
+
+        self.chunk_pairs(other)
+            .map(|(lhs, rhs)| (lhs ^ rhs).count_ones())
+            .sum()
+    }
+
+    ///
+    /// This routine returns `true` if every bit set in this slice is also set in `other`,
+    /// otherwise returns false. The shorter slice is treated as if it were extended with clear
+    /// bits to the length of the longer slice.
+    /// 
+    pub fn is_subset_of<OM: Mutability>(&self, other: &BitmapSliceImpl<'_, B, OM, O>) -> bool {
+        self.chunk_pairs(other).all(|(lhs, rhs)| (lhs & !rhs) == B::ZERO)
+    }
+
+    ///
+    /// This routine returns `true` if every bit set in `other` is also set in this slice,
+    /// otherwise returns false. The shorter slice is treated as if it were extended with clear
+    /// bits to the length of the longer slice.
+    /// 
+    pub fn is_superset_of<OM: Mutability>(&self, other: &BitmapSliceImpl<'_, B, OM, O>) -> bool {
+        other.is_subset_of(self)
+    }
+
+    ///
+    /// This routine returns `true` if this slice and `other` do not have any set bits in
+    /// common, otherwise returns false.
+    /// 
+    pub fn is_disjoint<OM: Mutability>(&self, other: &BitmapSliceImpl<'_, B, OM, O>) -> bool {
+        self.chunk_pairs(other).all(|(lhs, rhs)| (lhs & rhs) == B::ZERO)
+    }
+
+    ///
+    /// This routine returns a tuple containing the zero based index and the length of the
+    /// longest run of bits that are set in both this slice and `other`. The runs of both slices
+    /// are merged in a single pass, and ties are broken in favor of the lower index. If the
+    /// slices do not have any set bits in common, None is returned.
+    /// 
+    pub fn longest_common_set_run<OM: Mutability>(&self, other: &BitmapSliceImpl<'_, B, OM, O>) -> Option<(usize, usize)> {
+        let mut lhs_ranges = self.range_iter();
+        let mut rhs_ranges = other.range_iter();
+        let mut lhs_range = lhs_ranges.next();
+        let mut rhs_range = rhs_ranges.next();
+
+        let mut longest: Option<(usize, usize)> = None;
+        while let (Some((lhs_start, lhs_count)), Some((rhs_start, rhs_count))) = (lhs_range, rhs_range) {
+            let (lhs_end, rhs_end) = (lhs_start + lhs_count, rhs_start + rhs_count);
+            let common_start = core::cmp::max(lhs_start, rhs_start);
+            let common_end = core::cmp::min(lhs_end, rhs_end);
+            if (common_start < common_end) &&
+               longest.map_or(true, |(_, longest_count)| (common_end - common_start) > longest_count) {
+
+                longest = Some((common_start, common_end - common_start));
+            }
+
+            if lhs_end <= rhs_end {
+                lhs_range = lhs_ranges.next();
+
+            } else {
+                rhs_range = rhs_ranges.next();
+            }
+        }
+
+        longest
+    }
+
+    ///
+    /// Returns the bits of this slice packed into bytes in little endian bit order, where bit
+    /// `i` of this slice is bit `i % 8` of byte `i / 8`. The final byte is padded with clear
+    /// bits when the size of this slice is not a multiple of 8. The result does not depend on
+    /// the backing word type or the byte order of the platform.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; crate::polyfill::div_ceil(self.bit_count, 8)];
+        for (range_start, range_count) in self.range_iter() {
+            let range_end = range_start + range_count;
+            let full_bytes_start = crate::polyfill::div_ceil(range_start, 8);
+            let full_bytes_end = range_end / 8;
+            if full_bytes_start < full_bytes_end {
+                bytes[full_bytes_start..full_bytes_end].fill(0xFF);
+                for bit_index in range_start..(full_bytes_start * 8) {
+                    bytes[bit_index / 8] |= 1 << (bit_index % 8);
+                }
+
+                for bit_index in (full_bytes_end * 8)..range_end {
+                    bytes[bit_index / 8] |= 1 << (bit_index % 8);
+                }
+
+            } else {
+                for bit_index in range_start..range_end {
+                    bytes[bit_index / 8] |= 1 << (bit_index % 8);
+                }
+            }
+        }
+
+        bytes
+    }
+
     ///
     /// This routine splits this bitmap slice into two non-mutable subslices. The
     /// first slice starts at the same bit as this slice and ends at `bit_index` (exclusive).
     /// The second slice starts `bit_index` (inclusive) and ends at the same bit
     /// as this slice.
     /// 
-    pub fn split_at(self, bit_index: usize) -> (BitmapSliceImpl<'a, B, Const>, BitmapSliceImpl<'a, B, Const>) {
+    pub fn split_at(self, bit_index: usize) -> (BitmapSliceImpl<'a, B, Const, O>, BitmapSliceImpl<'a, B, Const, O>) {
         if bit_index > self.bit_count {
             panic!("Invalid bit index ({} > {})", bit_index, self.bit_count);
         }
@@ -191,63 +640,157 @@ impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
     }
 
     ///
-    /// This routine returns a [BitmapSlice](crate::slice::BitmapSlice) starting at the first bit
-    /// in the range (inclusive), and ending at the last bit in the range (exclusive).
+    /// This routine splits off the first bit of this slice, returning its value along with a
+    /// non-mutable subslice over the remaining bits. If this slice is empty, None is returned.
     /// 
-    pub fn subslice(&self, bit_range: Range<usize>) -> BitmapSliceImpl<B, Const> {
-        let (bit_start, bit_end, bit_count) = (bit_range.start, bit_range.end, bit_range.count());
-        if bit_start > bit_end {
-            panic!("Invalid bit range start ({}) > end ({})", bit_start, bit_end);
+    pub fn split_first(self) -> Option<(bool, BitmapSliceImpl<'a, B, Const, O>)> {
+        if self.bit_count == 0 {
+            return None;
+        }
 
-        } else if bit_count > self.bit_count {
-            panic!("Invalid bit range [{}:{}] for bit map slice of size {}",
-                   bit_start,
-                   bit_end,
-                   self.bit_count);
+        let first_bit = self.get_bit(0);
+        let (_, remaining) = self.split_at(1);
+        Some((first_bit, remaining))
+    }
+
+    ///
+    /// This routine splits off the last bit of this slice, returning its value along with a
+    /// non-mutable subslice over the preceding bits. If this slice is empty, None is returned.
+    /// 
+    pub fn split_last(self) -> Option<(bool, BitmapSliceImpl<'a, B, Const, O>)> {
+        if self.bit_count == 0 {
+            return None;
         }
 
-        let real_bit_start = bit_start + (self.first_bit_offset as usize);
-        let real_starting_slot = real_bit_start / B::BIT_COUNT;
-        let real_first_bit_offset = (real_bit_start % B::BIT_COUNT) as u8;
+        let last_index = self.bit_count - 1;
+        let last_bit = self.get_bit(last_index);
+        let (remaining, _) = self.split_at(last_index);
+        Some((last_bit, remaining))
+    }
 
-        unsafe {
-            let buffer_address = {
-                let mut buffer_address = self.buffer_address.as_ptr();
-                buffer_address = buffer_address.add(real_starting_slot);
-                NonNull::new_unchecked(buffer_address)
-            };
+    ///
+    /// This routine returns a [BitmapSlice](crate::slice::BitmapSlice) starting at the first bit
+    /// in the range (inclusive), and ending at the last bit in the range (exclusive).
+    /// 
+    pub fn subslice(&self, bit_range: Range<usize>) -> BitmapSliceImpl<'_, B, Const, O> {
+        let (bit_start, bit_end) = (bit_range.start, bit_range.end);
+        self.try_subslice(bit_range)
+            .unwrap_or_else(|| Self::invalid_subslice_range(bit_start, bit_end, self.bit_count))
+    }
 
-            BitmapSliceImpl::<B, Const>::from_raw_parts(buffer_address, real_first_bit_offset, bit_count)
+    ///
+    /// This routine returns a [BitmapSlice](crate::slice::BitmapSlice) starting at the first bit
+    /// in the range (inclusive), and ending at the last bit in the range (exclusive). If the
+    /// start of the range is past its end, or the range extends past the end of this slice,
+    /// None is returned. An empty range returns an empty slice.
+    /// 
+    pub fn try_subslice(&self, bit_range: Range<usize>) -> Option<BitmapSliceImpl<'_, B, Const, O>> {
+        let (real_first_bit_offset, buffer_address, bit_count) = self.subslice_parts(bit_range)?;
+        unsafe {
+            Some(BitmapSliceImpl::<B, Const, O>::from_raw_parts(buffer_address, real_first_bit_offset, bit_count))
         }
     }
 
     ///
     /// Converts this slice into a const slice.
     /// 
-    pub fn to_const_slice(self) -> BitmapSliceImpl<'a, B, Const> {
+    pub fn to_const_slice(self) -> BitmapSliceImpl<'a, B, Const, O> {
         unsafe {
-            BitmapSliceImpl::<'a, B, Const>::from_raw_parts(self.buffer_address, self.first_bit_offset, self.bit_count)
+            BitmapSliceImpl::<'a, B, Const, O>::from_raw_parts(self.buffer_address, self.first_bit_offset, self.bit_count)
         }
     }
 
 }
 
-impl<'a, B: BitStore, M: Mutability> BitmapOpts for BitmapSliceImpl<'a, B, M> {
+impl<'a, B: BitStore, M: Mutability, O: BitOrder> BitmapOpts for BitmapSliceImpl<'a, B, M, O> {
     
+    fn count_ones(&self) -> usize {
+        (0..self.slot_count())
+            .map(|slot| self.read_masked_slot(slot).count_ones())
+            .sum()
+    }
+
+    fn count_ones_in_range(&self, range: Range<usize>) -> usize {
+        self.count_in_range::<false>(range)
+    }
+
+    fn count_zeros_in_range(&self, range: Range<usize>) -> usize {
+        self.count_in_range::<true>(range)
+    }
+
     fn find_next_clear_in_range(&self, range: Range<usize>) -> Option<usize> {
         self.find_next_in_range::<true>(range)
     }
 
+    fn trailing_zeros(&self) -> usize {
+        let first_bit_offset = self.first_bit_offset as usize;
+        (0..self.slot_count())
+            .map(|slot| (slot, self.read_masked_slot(slot)))
+            .find(|(_, slot_contents)| *slot_contents != B::ZERO)
+            .map_or(self.bit_count, |(slot, slot_contents)| slot * B::BIT_COUNT + O::trailing_zeros(slot_contents) - first_bit_offset)
+    }
+
+    fn leading_zeros(&self) -> usize {
+        let real_bit_end = (self.first_bit_offset as usize) + self.bit_count;
+        (0..self.slot_count())
+            .rev()
+            .map(|slot| (slot, self.read_masked_slot(slot)))
+            .find(|(_, slot_contents)| *slot_contents != B::ZERO)
+            .map_or(self.bit_count, |(slot, slot_contents)| real_bit_end + O::leading_zeros(slot_contents) - (slot + 1) * B::BIT_COUNT)
+    }
+
+    fn for_each_clear_range<F: FnMut(usize, usize)>(&self, f: F)
+        where Self: Sized {
+        self.for_each_range_in_slots::<true, F>(f)
+    }
+
+    fn for_each_set_range<F: FnMut(usize, usize)>(&self, f: F)
+        where Self: Sized {
+
+        self.for_each_range_in_slots::<false, F>(f)
+    }
+
+    fn has_isolated_set_bits(&self) -> bool {
+        self.has_isolated_set_bits_in_chunks()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.all_bits_match::<true>()
+    }
+
+    fn is_full(&self) -> bool {
+        self.all_bits_match::<false>()
+    }
+
+    fn parity(&self) -> bool {
+        let folded_slots = (0..self.slot_count())
+            .fold(B::ZERO, |folded, slot| folded ^ self.read_masked_slot(slot));
+
+        (folded_slots.count_ones() & 1) != 0
+    }
+
     fn find_next_set_in_range(&self, range: Range<usize>) -> Option<usize> {
         self.find_next_in_range::<false>(range)
     }
 
+    fn find_prev_clear_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.find_prev_in_range::<true>(range)
+    }
+
+    fn find_prev_set_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.find_prev_in_range::<false>(range)
+    }
+
+    fn select(&self, n: usize) -> Option<usize> {
+        self.select_in_slots(n)
+    }
+
     fn get_bit(&self, bit_index: usize) -> bool {
         let (slot, offset) = self.translate_bit_index(bit_index);
         let slot_contents = 
             unsafe { self.buffer_address.as_ptr().add(slot).read() };
 
-        (slot_contents & B::create_bit_mask(offset)) != B::ZERO
+        (slot_contents & O::create_bit_mask::<B>(offset)) != B::ZERO
     }
 
     fn size(&self) -> usize {
@@ -256,7 +799,23 @@ impl<'a, B: BitStore, M: Mutability> BitmapOpts for BitmapSliceImpl<'a, B, M> {
     
 }
 
-impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceImpl<'a, B, Mut, O> {
+
+    ///
+    /// This routine returns every backing word spanned by this slice as a mutable slice. The
+    /// first and last words may contain bits outside of this slice, and writing to them
+    /// modifies those bits as well.
+    /// 
+    /// # Safety
+    /// 
+    /// Slices split with [split_at_mut](Self::split_at_mut) share the word holding the split
+    /// point. The caller must ensure that no other slice accesses the first or last word of
+    /// this slice while the returned words are alive, and that bits outside of this slice
+    /// are only written if no other slice relies on their values.
+    /// 
+    pub unsafe fn as_word_slice_mut(&mut self) -> &mut [B] {
+        core::slice::from_raw_parts_mut(self.buffer_address.as_ptr(), self.slot_count())
+    }
 
     ///
     /// This routine splits this bitmap slice into two mutable subslices. The first
@@ -264,7 +823,7 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
     /// The second slice starts `bit_index` (inclusive) and ends at the same bit
     /// as this slice.
     /// 
-    pub fn split_at_mut(self, bit_index: usize) -> (BitmapSliceImpl<'a, B, Mut>, BitmapSliceImpl<'a, B, Mut>) {
+    pub fn split_at_mut(self, bit_index: usize) -> (BitmapSliceImpl<'a, B, Mut, O>, BitmapSliceImpl<'a, B, Mut, O>) {
         if bit_index > self.bit_count {
             panic!("Invalid bit index ({} > {})", bit_index, self.bit_count);
         }
@@ -297,36 +856,238 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
     /// first bit in the range (inclusive), and ending at the last bit in the range
     /// (exclusive).
     /// 
-    pub fn subslice_mut(&mut self, bit_range: Range<usize>) -> BitmapSliceImpl<B, Mut> {
-        let (bit_start, bit_end, bit_count) = (bit_range.start, bit_range.end, bit_range.count());
-        if bit_start > bit_end {
-            panic!("Invalid bit range start ({}) > end ({})", bit_start, bit_end);
+    pub fn subslice_mut(&mut self, bit_range: Range<usize>) -> BitmapSliceImpl<'_, B, Mut, O> {
+        let (bit_start, bit_end, size) = (bit_range.start, bit_range.end, self.bit_count);
+        self.try_subslice_mut(bit_range)
+            .unwrap_or_else(|| Self::invalid_subslice_range(bit_start, bit_end, size))
+    }
+
+    ///
+    /// This routine returns a [BitmapSliceMut](crate::slice::BitmapSliceMut) starting at the
+    /// first bit in the range (inclusive), and ending at the last bit in the range (exclusive).
+    /// If the start of the range is past its end, or the range extends past the end of this
+    /// slice, None is returned. An empty range returns an empty slice.
+    /// 
+    pub fn try_subslice_mut(&mut self, bit_range: Range<usize>) -> Option<BitmapSliceImpl<'_, B, Mut, O>> {
+        let (real_first_bit_offset, buffer_address, bit_count) = self.subslice_parts(bit_range)?;
+        unsafe {
+            Some(BitmapSliceImpl::<B, Mut, O>::from_raw_parts(buffer_address, real_first_bit_offset, bit_count))
+        }
+    }
+
+    ///
+    /// Returns an iterator over every bit of this slice, yielding a [BitRef](super::BitRef) proxy per bit
+    /// that can be used to read or write that bit.
+    /// 
+    pub fn iter_mut(&mut self) -> BitmapSliceIterMut<'_, B, O> {
+        BitmapSliceIterMut::new(self.subslice_mut(0..self.bit_count))
+    }
+
+    ///
+    /// Returns an iterator over consecutive mutable subslices of this slice, each `chunk_bits`
+    /// bits long. The last subslice is shorter if the size of this slice is not a multiple of
+    /// `chunk_bits`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `chunk_bits` is zero.
+    /// 
+    pub fn chunks_mut(&mut self, chunk_bits: usize) -> BitmapChunksMut<'_, B, O> {
+        if chunk_bits == 0 {
+            panic!("Invalid chunk size ({})", chunk_bits);
+        }
+
+        BitmapChunksMut::new(self.subslice_mut(0..self.bit_count), chunk_bits)
+    }
 
-        } else if bit_count > self.bit_count {
-            panic!("Invalid bit range [{}:{}] for bit map slice of size {}",
-                   bit_start,
-                   bit_end,
+    ///
+    /// This routine clears the bit at the provided index without checking that the index is
+    /// within this slice.
+    /// 
+    /// # Safety
+    /// 
+    /// The caller must ensure that `bit_index < self.size()`. Passing an index past the end
+    /// of this slice writes outside of the backing buffer and is undefined behavior.
+    /// 
+    #[inline(always)]
+    pub unsafe fn clear_bit_unchecked(&mut self, bit_index: usize) {
+        self.modify_bit_unchecked(bit_index, BitmapSliceOperation::Clear);
+    }
+
+    ///
+    /// This routine sets the bit at the provided index without checking that the index is
+    /// within this slice.
+    /// 
+    /// # Safety
+    /// 
+    /// The caller must ensure that `bit_index < self.size()`. Passing an index past the end
+    /// of this slice writes outside of the backing buffer and is undefined behavior.
+    /// 
+    #[inline(always)]
+    pub unsafe fn set_bit_unchecked(&mut self, bit_index: usize) {
+        self.modify_bit_unchecked(bit_index, BitmapSliceOperation::Set);
+    }
+
+    ///
+    /// This routine toggles the bit at the provided index without checking that the index is
+    /// within this slice.
+    /// 
+    /// # Safety
+    /// 
+    /// The caller must ensure that `bit_index < self.size()`. Passing an index past the end
+    /// of this slice writes outside of the backing buffer and is undefined behavior.
+    /// 
+    #[inline(always)]
+    pub unsafe fn toggle_bit_unchecked(&mut self, bit_index: usize) {
+        self.modify_bit_unchecked(bit_index, BitmapSliceOperation::Toggle);
+    }
+
+    ///
+    /// This routine rotates every bit in this slice `n` positions towards higher bit indices,
+    /// so bit `i` moves to bit `(i + n) % size()`. Bits rotated past the end of this slice
+    /// reappear at the start. Rotating by a multiple of `size()` leaves this slice unchanged.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.bit_count == 0 {
+            return;
+        }
+
+        let n = n % self.bit_count;
+        if n != 0 {
+            self.rotate_bits_left(n);
+        }
+    }
+
+    ///
+    /// This routine rotates every bit in this slice `n` positions towards lower bit indices,
+    /// so bit `i` moves to bit `(i + size() - n % size()) % size()`. Bits rotated past the
+    /// start of this slice reappear at the end. Rotating by a multiple of `size()` leaves this
+    /// slice unchanged.
+    /// 
+    #[cfg(feature = "alloc")]
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.bit_count == 0 {
+            return;
+        }
+
+        let n = n % self.bit_count;
+        if n != 0 {
+            self.rotate_bits_left(self.bit_count - n);
+        }
+    }
+
+    ///
+    /// This routine reverses the order of the bits in this slice, so bit `i` swaps with bit
+    /// `size() - 1 - i`.
+    /// 
+    pub fn reverse(&mut self) {
+        self.reverse_in_chunks();
+    }
+
+    ///
+    /// This routine shifts every bit in this slice `n` positions towards higher bit indices,
+    /// so bit `i` moves to bit `i + n`. Bits shifted past the end of this slice are discarded
+    /// and the vacated low bits are cleared. Shifting by `size()` or more clears every bit.
+    /// 
+    pub fn shift_left(&mut self, n: usize) {
+        if n >= self.bit_count {
+            self.clear_all();
+            return;
+        }
+
+        self.copy_bits_within(0..(self.bit_count - n), n);
+        self.clear_bit_range(0..n);
+    }
+
+    ///
+    /// This routine shifts every bit in this slice `n` positions towards lower bit indices,
+    /// so bit `i` moves to bit `i - n`. Bits shifted past the start of this slice are discarded
+    /// and the vacated high bits are cleared. Shifting by `size()` or more clears every bit.
+    /// 
+    pub fn shift_right(&mut self, n: usize) {
+        if n >= self.bit_count {
+            self.clear_all();
+            return;
+        }
+
+        self.copy_bits_within(n..self.bit_count, 0);
+        self.clear_bit_range((self.bit_count - n)..self.bit_count);
+    }
+
+    ///
+    /// This routine copies the bits in `src_range` of `src` into this slice starting at
+    /// `dest_start`. The source and destination do not need to share the same alignment
+    /// within a backing word. If the source and destination overlap, the copy is performed
+    /// in the direction that preserves the source bits, as if the source was first copied
+    /// to a temporary buffer.
+    /// 
+    pub fn copy_bits_from(&mut self, dest_start: usize, src: &BitmapSlice<'_, B, O>, src_range: Range<usize>) {
+        if src_range.start > src_range.end {
+            panic!("Invalid bit range start ({}) > end ({})", src_range.start, src_range.end);
+
+        } else if src_range.end > src.bit_count {
+            panic!("Invalid bit range [{}:{}] for bitmap of size {}",
+                   src_range.start,
+                   src_range.end,
+                   src.bit_count);
+        }
+
+        let bit_count = src_range.len();
+        if dest_start.checked_add(bit_count).map_or(true, |dest_end| dest_end > self.bit_count) {
+            panic!("Invalid bit range [{}:{}] for bitmap of size {}",
+                   dest_start,
+                   dest_start.saturating_add(bit_count),
                    self.bit_count);
         }
 
-        let real_bit_start = bit_start + (self.first_bit_offset as usize);
-        let real_starting_slot = real_bit_start / B::BIT_COUNT;
-        let real_first_bit_offset = (real_bit_start % B::BIT_COUNT) as u8;
+        let global_bit_index = |buffer_address: NonNull<B>, first_bit_offset: u8, bit_index: usize| {
+            ((buffer_address.as_ptr() as usize) / core::mem::size_of::<B>()) * B::BIT_COUNT +
+                (first_bit_offset as usize) +
+                bit_index
+        };
 
-        unsafe {
-            let buffer_address = {
-                let mut buffer_address = self.buffer_address.as_ptr();
-                buffer_address = buffer_address.add(real_starting_slot);
-                NonNull::new_unchecked(buffer_address)
-            };
+        let copy_backwards =
+            global_bit_index(self.buffer_address, self.first_bit_offset, dest_start) >
+            global_bit_index(src.buffer_address, src.first_bit_offset, src_range.start);
+
+        if copy_backwards {
+            let mut remaining_bit_count = bit_count;
+            while remaining_bit_count > 0 {
+                let chunk_bit_count = core::cmp::min(remaining_bit_count, B::BIT_COUNT);
+                remaining_bit_count -= chunk_bit_count;
 
-            BitmapSliceImpl::<B, Mut>::from_raw_parts(buffer_address, real_first_bit_offset, bit_count)
+                let bits = src.read_bit_chunk(src_range.start + remaining_bit_count, chunk_bit_count);
+                self.write_bit_chunk(dest_start + remaining_bit_count, chunk_bit_count, bits);
+            }
+
+        } else {
+            let mut copied_bit_count = 0;
+            while copied_bit_count < bit_count {
+                let chunk_bit_count = core::cmp::min(bit_count - copied_bit_count, B::BIT_COUNT);
+
+                let bits = src.read_bit_chunk(src_range.start + copied_bit_count, chunk_bit_count);
+                self.write_bit_chunk(dest_start + copied_bit_count, chunk_bit_count, bits);
+                copied_bit_count += chunk_bit_count;
+            }
         }
     }
 
+    ///
+    /// This routine copies the bits in `src_range` of this slice to the bits starting at
+    /// `dest_start` of this slice. The source and destination ranges may overlap.
+    /// 
+    pub fn copy_bits_within(&mut self, src_range: Range<usize>, dest_start: usize) {
+        let src = unsafe {
+            BitmapSliceImpl::<B, Const, O>::from_raw_parts(self.buffer_address, self.first_bit_offset, self.bit_count)
+        };
+
+        self.copy_bits_from(dest_start, &src, src_range);
+    }
+
 }
 
-impl<'a, B: BitStore> BitmapOptsMut for BitmapSliceImpl<'a, B, Mut> {
+impl<'a, B: BitStore, O: BitOrder> BitmapOptsMut for BitmapSliceImpl<'a, B, Mut, O> {
     
     ///
     /// This routine clears the bit at the provided index.
@@ -342,6 +1103,37 @@ impl<'a, B: BitStore> BitmapOptsMut for BitmapSliceImpl<'a, B, Mut> {
         self.modify_bit_range(bit_range, BitmapSliceOperation::Clear);
     }
 
+    ///
+    /// This routine clears every bit in this slice. Whole slots are cleared directly and only
+    /// the partial first and last slots are masked.
+    /// 
+    fn clear_all(&mut self) {
+        self.modify_all(BitmapSliceOperation::Clear);
+    }
+
+    ///
+    /// This routine clears every bit outside of `range` in a single masked pass over the
+    /// backing slots. Slots entirely within `range` are not written.
+    /// 
+    fn keep_range(&mut self, range: Range<usize>) {
+        self.clear_outside_range(range);
+    }
+
+    ///
+    /// This routine toggles every bit in this slice.
+    /// 
+    fn invert(&mut self) {
+        self.modify_all(BitmapSliceOperation::Toggle);
+    }
+
+    ///
+    /// This routine sets every bit in this slice. Whole slots are set directly and only the
+    /// partial first and last slots are masked.
+    /// 
+    fn set_all(&mut self) {
+        self.modify_all(BitmapSliceOperation::Set);
+    }
+
     ///
     /// This routine sets the bit at the provided index.
     /// 
@@ -355,6 +1147,30 @@ impl<'a, B: BitStore> BitmapOptsMut for BitmapSliceImpl<'a, B, Mut> {
     fn set_bit_range(&mut self, bit_range: Range<usize>) {
         self.modify_bit_range(bit_range, BitmapSliceOperation::Set);
     }
+
+    ///
+    /// This routine exchanges the values of the bits at indices `i` and `j`. When both bits
+    /// are held in the same slot, they are swapped with a single read and write of that slot.
+    /// 
+    fn swap_bits(&mut self, i: usize, j: usize) {
+        let (slot_i, offset_i) = self.translate_bit_index(i);
+        let (slot_j, offset_j) = self.translate_bit_index(j);
+        let (mask_i, mask_j) = (O::create_bit_mask::<B>(offset_i), O::create_bit_mask::<B>(offset_j));
+        let buffer = self.buffer_address.as_ptr();
+
+        unsafe {
+            if slot_i == slot_j {
+                let slot_contents = buffer.add(slot_i).read();
+                if ((slot_contents & mask_i) == B::ZERO) != ((slot_contents & mask_j) == B::ZERO) {
+                    buffer.add(slot_i).write(slot_contents ^ (mask_i | mask_j));
+                }
+
+            } else if ((buffer.add(slot_i).read() & mask_i) == B::ZERO) != ((buffer.add(slot_j).read() & mask_j) == B::ZERO) {
+                BitmapSliceOperation::Toggle.apply(buffer.add(slot_i), mask_i);
+                BitmapSliceOperation::Toggle.apply(buffer.add(slot_j), mask_j);
+            }
+        }
+    }
     
     ///
     /// This routine toggles the bit at the provided index.
@@ -370,4 +1186,13 @@ impl<'a, B: BitStore> BitmapOptsMut for BitmapSliceImpl<'a, B, Mut> {
         self.modify_bit_range(bit_range, BitmapSliceOperation::Toggle);
     }
 
+    ///
+    /// This routine toggles the bit at every index in `indices`. Consecutive indices that fall
+    /// in the same backing word are combined into a single mask, so sorted indices are toggled
+    /// one word at a time. Duplicate indices cancel out.
+    /// 
+    fn toggle_bits(&mut self, indices: &[usize]) {
+        self.toggle_bits_grouped(indices);
+    }
+
 }
@@ -0,0 +1,97 @@
+
+use super::BitmapSlice;
+
+use crate::store::BitStore;
+use crate::traits::BitmapOpts;
+
+use core::ops::Range;
+
+use alloc::vec::Vec;
+
+///
+/// Implements a read-only bitmap over a chain of non-contiguous bitmap slices. The segments
+/// are addressed as if they were laid end to end, so bit 0 of the second segment directly
+/// follows the last bit of the first segment.
+/// 
+pub struct ChainedBitmap<'a, B: BitStore = usize> {
+    segments: Vec<BitmapSlice<'a, B>>,
+    segment_starts: Vec<usize>,
+    bit_count: usize
+}
+
+impl<'a, B: BitStore> ChainedBitmap<'a, B> {
+
+    ///
+    /// Creates a new chained bitmap over the provided segments, in order.
+    /// 
+    pub fn new(segments: Vec<BitmapSlice<'a, B>>) -> Self {
+        let mut bit_count = 0;
+        let segment_starts = segments.iter()
+            .map(|segment| {
+                let segment_start = bit_count;
+                bit_count += segment.size();
+                segment_start
+            })
+            .collect();
+
+        ChainedBitmap { segments, segment_starts, bit_count }
+    }
+
+    ///
+    /// Returns the segments making up this chained bitmap.
+    /// 
+    pub fn segments(&self) -> &[BitmapSlice<'a, B>] {
+        &self.segments
+    }
+
+    fn find_next_in_range<F>(&self, range: Range<usize>, find: F) -> Option<usize>
+        where F: Fn(&BitmapSlice<'a, B>, Range<usize>) -> Option<usize> {
+
+        if range.is_empty() {
+            return None;
+
+        } else if range.end > self.bit_count {
+            panic!("Invalid bit range [{}:{}] for bitmap of size {}",
+                   range.start,
+                   range.end,
+                   self.bit_count);
+        }
+
+        let first_segment = self.segment_starts.partition_point(|start| *start <= range.start) - 1;
+        self.segments[first_segment..].iter()
+            .zip(&self.segment_starts[first_segment..])
+            .take_while(|(_, segment_start)| **segment_start < range.end)
+            .find_map(|(segment, segment_start)| {
+                let local_start = range.start.saturating_sub(*segment_start);
+                let local_end = (range.end - segment_start).min(segment.size());
+
+                find(segment, local_start..local_end).map(|bit_index| segment_start + bit_index)
+            })
+    }
+
+}
+
+impl<'a, B: BitStore> BitmapOpts for ChainedBitmap<'a, B> {
+
+    fn find_next_clear_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.find_next_in_range(range, |segment, range| segment.find_next_clear_in_range(range))
+    }
+
+    fn find_next_set_in_range(&self, range: Range<usize>) -> Option<usize> {
+        self.find_next_in_range(range, |segment, range| segment.find_next_set_in_range(range))
+    }
+
+    fn get_bit(&self, bit_index: usize) -> bool {
+        if bit_index >= self.bit_count {
+            panic!("Invalid bit index ({} > {})", bit_index, self.bit_count);
+        }
+
+        let segment = self.segment_starts.partition_point(|start| *start <= bit_index) - 1;
+        self.segments[segment].get_bit(bit_index - self.segment_starts[segment])
+    }
+
+    fn size(&self) -> usize {
+        self.bit_count
+    }
+
+}
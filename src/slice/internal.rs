@@ -1,12 +1,15 @@
 
 use super::BitmapSliceImpl;
 use crate::polyfill::{Mut, Mutability};
-use crate::store::BitStore;
+use crate::store::{BitOrder, BitStore};
 use crate::traits::BitmapOpts;
 
-use std::marker::PhantomData;
-use std::ops::Range;
-use std::ptr::{self, NonNull};
+use core::fmt;
+use core::ops::Range;
+use core::ptr::{self, NonNull};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[derive(Clone, Copy, Debug)]
 pub(super) enum BitmapSliceOperation {
@@ -26,9 +29,180 @@ impl BitmapSliceOperation {
         }
     }
 
+    #[inline(always)]
+    pub(super) unsafe fn apply_full<B: BitStore>(&self, target: *mut B) {
+        match self {
+            BitmapSliceOperation::Clear => *target = B::ZERO,
+            BitmapSliceOperation::Set => *target = B::MAX,
+            BitmapSliceOperation::Toggle => *target = !*target
+        }
+    }
+
 }
 
-impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
+impl<'a, B: BitStore, M: Mutability, O: BitOrder> BitmapSliceImpl<'a, B, M, O> {
+
+    pub(super) fn all_bits_match<const CLEAR_BIT: bool>(&self) -> bool {
+        let real_range = (self.first_bit_offset as usize)..((self.first_bit_offset as usize) + self.bit_count);
+        let slot_count = self.slot_count();
+
+        let mut buffer = self.buffer_address.as_ptr();
+        for current_slot in 0..slot_count {
+            let mut current_bits = unsafe { ptr::read(buffer) };
+            if CLEAR_BIT {
+                current_bits = !current_bits;
+            }
+
+            if (current_slot == 0) || ((current_slot + 1) == slot_count) {
+                let slot_mask = Self::slot_mask(current_slot, &real_range);
+                if (current_bits & slot_mask) != slot_mask {
+                    return false;
+                }
+
+            } else if current_bits != B::MAX {
+                return false;
+            }
+
+            buffer = unsafe { buffer.add(1) };
+        }
+
+        true
+    }
+
+    pub(super) fn count_in_range<const CLEAR_BIT: bool>(&self, range: Range<usize>) -> usize {
+        if range.is_empty() {
+            return 0;
+
+        } else {
+            let total_bit_count = self.size();
+            if range.end > total_bit_count {
+                panic!("Invalid bit range [{}:{}] for bitmap of size {}",
+                       range.start,
+                       range.end,
+                       total_bit_count);
+            }
+        }
+
+        let real_range = (range.start + (self.first_bit_offset as usize))..(range.end + (self.first_bit_offset as usize));
+        let starting_slot = real_range.start / B::BIT_COUNT;
+        let ending_slot = crate::polyfill::div_ceil(real_range.end, B::BIT_COUNT);
+
+        let mut count = 0;
+        let mut buffer = unsafe { self.buffer_address.as_ptr().add(starting_slot) };
+        for current_slot in starting_slot..ending_slot {
+            let mut current_bits = unsafe { ptr::read(buffer) };
+            if CLEAR_BIT {
+                current_bits = !current_bits;
+            }
+
+            if (current_slot == starting_slot) || ((current_slot + 1) == ending_slot) {
+                current_bits &= Self::slot_mask(current_slot, &real_range);
+            }
+
+            count += current_bits.count_ones();
+            buffer = unsafe { buffer.add(1) };
+        }
+
+        count
+    }
+
+    pub(super) fn for_each_range_in_slots<const CLEAR_BIT: bool, F: FnMut(usize, usize)>(&self, mut f: F) {
+        let logical_start = self.first_bit_offset as usize;
+        let real_range = logical_start..(logical_start + self.bit_count);
+        let buffer = self.buffer_address.as_ptr();
+
+        let mut run_start = None;
+        for current_slot in 0..self.slot_count() {
+            let slot_contents = unsafe { buffer.add(current_slot).read() };
+            let slot_contents = if CLEAR_BIT { !slot_contents } else { slot_contents };
+            let matching_bits = slot_contents & Self::slot_mask(current_slot, &real_range);
+            let slot_start = current_slot * B::BIT_COUNT;
+
+            let mut offset = 0;
+            while offset < B::BIT_COUNT {
+                match run_start {
+                    Some(range_start) => {
+                        let run_length = O::trailing_zeros(O::shift_right(!matching_bits, offset));
+                        if (offset + run_length) >= B::BIT_COUNT {
+                            break;
+                        }
+
+                        offset += run_length;
+                        f(range_start - logical_start, slot_start + offset - range_start);
+                        run_start = None;
+                    },
+                    None => {
+                        let gap_length = O::trailing_zeros(O::shift_right(matching_bits, offset));
+                        if (offset + gap_length) >= B::BIT_COUNT {
+                            break;
+                        }
+
+                        offset += gap_length;
+                        run_start = Some(slot_start + offset);
+                    }
+                }
+            }
+        }
+
+        if let Some(range_start) = run_start {
+            f(range_start - logical_start, real_range.end - range_start);
+        }
+    }
+
+    pub(super) fn has_isolated_set_bits_in_chunks(&self) -> bool {
+        let mut previous_bit_set = false;
+        let mut bit_index = 0;
+        while bit_index < self.bit_count {
+            let chunk_bit_count = core::cmp::min(B::BIT_COUNT, self.bit_count - bit_index);
+            let chunk = self.read_bit_chunk(bit_index, chunk_bit_count);
+            let chunk_end = bit_index + chunk_bit_count;
+
+            let (mut left_neighbors, mut right_neighbors) = if B::BIT_COUNT > 1 {
+                (O::shift_left(chunk, 1), O::shift_right(chunk, 1))
+
+            } else {
+                (B::ZERO, B::ZERO)
+            };
+
+            if previous_bit_set {
+                left_neighbors |= O::create_bit_mask::<B>(0);
+            }
+
+            if (chunk_end < self.bit_count) && self.get_bit(chunk_end) {
+                right_neighbors |= O::create_bit_mask::<B>(chunk_bit_count - 1);
+            }
+
+            if (chunk & !left_neighbors & !right_neighbors) != B::ZERO {
+                return true;
+            }
+
+            previous_bit_set = (chunk & O::create_bit_mask::<B>(chunk_bit_count - 1)) != B::ZERO;
+            bit_index = chunk_end;
+        }
+
+        false
+    }
+
+    pub(super) fn select_in_slots(&self, n: usize) -> Option<usize> {
+        let mut remaining_count = n;
+        for current_slot in 0..self.slot_count() {
+            let mut current_bits = self.read_masked_slot(current_slot);
+            let slot_count = current_bits.count_ones();
+            if remaining_count >= slot_count {
+                remaining_count -= slot_count;
+                continue;
+            }
+
+            for _ in 0..remaining_count {
+                current_bits &= !O::create_bit_mask::<B>(O::trailing_zeros(current_bits));
+            }
+
+            let real_bit_index = (current_slot * B::BIT_COUNT) + O::trailing_zeros(current_bits);
+            return Some(real_bit_index - (self.first_bit_offset as usize));
+        }
+
+        None
+    }
 
     pub(super) fn find_next_in_range<const CLEAR_BIT: bool>(&self, range: Range<usize>) -> Option<usize> {
         if range.is_empty() {
@@ -36,7 +210,7 @@ impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
 
         } else {
             let total_bit_count = self.size();
-            if (range.end - range.start) > total_bit_count {
+            if range.end > total_bit_count {
                 panic!("Invalid bit range [{}:{}] for bitmap of size {}",
                        range.start,
                        range.end,
@@ -51,11 +225,20 @@ impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
         let mut current_slot = starting_slot;
         let mut buffer = unsafe { self.buffer_address.as_ptr().add(starting_slot) };
         while current_slot < ending_slot {
+            if current_slot != starting_slot {
+                let next_slot = self.skip_uniform_slots::<CLEAR_BIT>(current_slot, ending_slot);
+                buffer = unsafe { buffer.add(next_slot - current_slot) };
+                current_slot = next_slot;
+                if current_slot == ending_slot {
+                    break;
+                }
+            }
+
             let current_bits = {
                 let mut current_bits = unsafe { ptr::read(buffer) };
 
                 if current_slot == starting_slot {
-                    let mask = B::create_range_mask(0, starting_offset);
+                    let mask = O::create_range_mask::<B>(0, starting_offset);
                     if CLEAR_BIT {
                         current_bits |= mask;
 
@@ -73,9 +256,9 @@ impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
 
             if current_bits != B::ZERO {
                 let mut first_matching_bit = current_slot * B::BIT_COUNT;
-                first_matching_bit += current_bits.trailing_zeros() as usize;
+                first_matching_bit += O::trailing_zeros(current_bits) as usize;
                 first_matching_bit -= self.first_bit_offset as usize;
-                if first_matching_bit < ending_bit {
+                if first_matching_bit < range.end {
                     return Some(first_matching_bit);
 
                 } else {
@@ -90,37 +273,403 @@ impl<'a, B: BitStore, M: Mutability> BitmapSliceImpl<'a, B, M> {
         None
     }
 
-    pub(super) unsafe fn from_raw_parts(buffer_address: NonNull<B>, first_bit_offset: u8, bit_count: usize) -> Self {
-        
-        debug_assert!((first_bit_offset as usize) < B::BIT_COUNT);
+    ///
+    /// Returns the first slot in `starting_slot..ending_slot` that may contain a matching bit,
+    /// skipping slots that are entirely clear (or entirely set when searching for clear bits).
+    /// When `B` is narrower than `usize`, slots are compared a `usize` at a time so that long
+    /// uniform runs of a byte-width buffer are skipped in bulk. Any slots that do not fill a
+    /// whole `usize` are left for the caller to scan. Slots whose size does not divide the size
+    /// of a `usize`, such as `ByteArray<3>`, are never skipped in bulk, as a `usize` read would
+    /// then cover part of a slot past `ending_slot`.
+    /// 
+    #[inline(always)]
+    fn skip_uniform_slots<const CLEAR_BIT: bool>(&self, starting_slot: usize, ending_slot: usize) -> usize {
+        let slot_size = core::mem::size_of::<B>();
+        if (slot_size >= core::mem::size_of::<usize>()) || ((core::mem::size_of::<usize>() % slot_size) != 0) {
+            return starting_slot;
+        }
+
+        let skip_slot = if CLEAR_BIT { B::MAX } else { B::ZERO };
+        let mut skip_slot_bytes = [0u8; core::mem::size_of::<usize>()];
+        skip_slot.write_le_bytes(&mut skip_slot_bytes[..B::BYTE_COUNT]);
+        let skip_word = usize::from_ne_bytes([skip_slot_bytes[0]; core::mem::size_of::<usize>()]);
+
+        let slots_per_word = core::mem::size_of::<usize>() / slot_size;
+        let buffer = self.buffer_address.as_ptr();
+        let mut current_slot = starting_slot;
+        while (current_slot + slots_per_word) <= ending_slot {
+            let current_word = unsafe { ptr::read_unaligned(buffer.add(current_slot) as *const usize) };
+            if current_word != skip_word {
+                break;
+            }
+
+            current_slot += slots_per_word;
+        }
+
+        current_slot
+    }
+
+    pub(super) fn find_prev_in_range<const CLEAR_BIT: bool>(&self, range: Range<usize>) -> Option<usize> {
+        if range.is_empty() {
+            return None;
+
+        } else {
+            let total_bit_count = self.size();
+            if range.end > total_bit_count {
+                panic!("Invalid bit range [{}:{}] for bitmap of size {}",
+                       range.start,
+                       range.end,
+                       total_bit_count);
+            }
+        }
+
+        let real_range = (range.start + (self.first_bit_offset as usize))..(range.end + (self.first_bit_offset as usize));
+        let starting_slot = real_range.start / B::BIT_COUNT;
+        let ending_slot = (real_range.end - 1) / B::BIT_COUNT;
+
+        let mut current_slot = ending_slot;
+        let mut buffer = unsafe { self.buffer_address.as_ptr().add(ending_slot) };
+        loop {
+            let current_bits = {
+                let mut current_bits = unsafe { ptr::read(buffer) };
+                if CLEAR_BIT {
+                    current_bits = !current_bits;
+                }
+
+                if (current_slot == starting_slot) || (current_slot == ending_slot) {
+                    current_bits &= Self::slot_mask(current_slot, &real_range);
+                }
+
+                current_bits
+            };
+
+            if current_bits != B::ZERO {
+                let mut last_matching_bit = current_slot * B::BIT_COUNT;
+                last_matching_bit += B::BIT_COUNT - 1 - O::leading_zeros(current_bits);
+                last_matching_bit -= self.first_bit_offset as usize;
+                return Some(last_matching_bit);
+            }
+
+            if current_slot == starting_slot {
+                return None;
+            }
+
+            current_slot -= 1;
+            buffer = unsafe { buffer.sub(1) };
+        }
+    }
+
+    ///
+    /// Writes up to `maximum_bit_count` bits of this slice as `0`/`1` characters starting
+    /// from bit 0, separating every `group_size` bits with a space when `group_size` is nonzero.
+    /// If this slice is larger than `maximum_bit_count`, an ellipsis is written after the
+    /// last written bit.
+    /// 
+    pub(crate) fn write_bits(&self, f: &mut fmt::Formatter<'_>, group_size: usize, maximum_bit_count: usize) -> fmt::Result {
+        let written_bit_count = core::cmp::min(self.bit_count, maximum_bit_count);
+        for bit_index in 0..written_bit_count {
+            if (group_size != 0) && (bit_index != 0) && ((bit_index % group_size) == 0) {
+                f.write_str(" ")?;
+            }
+
+            f.write_str(if self.get_bit(bit_index) { "1" } else { "0" })?;
+        }
+
+        if written_bit_count < self.bit_count {
+            f.write_str(" ...")?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn read_masked_slot(&self, slot: usize) -> B {
+        debug_assert!(slot < self.slot_count());
+
+        let logical_start = self.first_bit_offset as usize;
+        let slot_contents =
+            unsafe { self.buffer_address.as_ptr().add(slot).read() };
+
+        slot_contents & Self::slot_mask(slot, &(logical_start..(logical_start + self.bit_count)))
+    }
+
+    ///
+    /// Reads `bit_count` bits starting at `bit_index` into the low bits of a single value,
+    /// combining the two slots the bits span when they are not slot aligned. The caller must
+    /// ensure that bit_count <= B::BIT_COUNT and the bits are within this slice.
+    /// 
+    pub(super) fn read_bit_chunk(&self, bit_index: usize, bit_count: usize) -> B {
+        debug_assert!((bit_count <= B::BIT_COUNT) && ((bit_index + bit_count) <= self.bit_count));
+
+        let real_bit_index = bit_index + (self.first_bit_offset as usize);
+        let (slot, offset) = (real_bit_index / B::BIT_COUNT, real_bit_index % B::BIT_COUNT);
+        let buffer = unsafe { self.buffer_address.as_ptr().add(slot) };
+
+        let mut bits = O::shift_right(unsafe { ptr::read(buffer) }, offset);
+        if (offset + bit_count) > B::BIT_COUNT {
+            bits |= O::shift_left(unsafe { ptr::read(buffer.add(1)) }, B::BIT_COUNT - offset);
+        }
+
+        bits & O::create_range_mask::<B>(0, bit_count)
+    }
+
+    ///
+    /// Returns an iterator over pairs of `B::BIT_COUNT` sized chunks read from the logical bits
+    /// of this slice and `other`, starting at bit 0 of both. The shorter slice is treated as if
+    /// it were extended with clear bits to the length of the longer slice.
+    /// 
+    pub(super) fn chunk_pairs<'s, OM: Mutability>(&'s self, other: &'s BitmapSliceImpl<'_, B, OM, O>) -> impl Iterator<Item = (B, B)> + 's {
+        let bit_count = core::cmp::max(self.bit_count, other.bit_count);
+        (0..crate::polyfill::div_ceil(bit_count, B::BIT_COUNT))
+            .map(move |chunk| {
+                let chunk_start = chunk * B::BIT_COUNT;
+                (self.read_bit_chunk_or_zero(chunk_start), other.read_bit_chunk_or_zero(chunk_start))
+            })
+    }
+
+    fn read_bit_chunk_or_zero(&self, bit_index: usize) -> B {
+        if bit_index >= self.bit_count {
+            B::ZERO
 
-        BitmapSliceImpl {
-            buffer_address,
-            bit_count,
-            first_bit_offset,
-            _lt: PhantomData::default(),
-            _mut: PhantomData::default()
+        } else {
+            self.read_bit_chunk(bit_index, core::cmp::min(B::BIT_COUNT, self.bit_count - bit_index))
         }
     }
 
+    pub(super) fn slot_mask(slot: usize, real_range: &Range<usize>) -> B {
+        let slot_start = slot * B::BIT_COUNT;
+        let mask_start = core::cmp::max(real_range.start, slot_start) - slot_start;
+        let mask_end = core::cmp::min(real_range.end, slot_start + B::BIT_COUNT) - slot_start;
+
+        O::create_range_mask::<B>(mask_start, mask_end - mask_start)
+    }
+
+    pub(super) fn slot_count(&self) -> usize {
+        crate::polyfill::div_ceil((self.first_bit_offset as usize) + self.bit_count, B::BIT_COUNT)
+    }
+
+    pub(super) fn subslice_parts(&self, bit_range: Range<usize>) -> Option<(u8, NonNull<B>, usize)> {
+        if bit_range.start > bit_range.end || bit_range.end > self.bit_count {
+            return None;
+        }
+
+        let real_bit_start = bit_range.start + (self.first_bit_offset as usize);
+        let real_starting_slot = real_bit_start / B::BIT_COUNT;
+        let real_first_bit_offset = (real_bit_start % B::BIT_COUNT) as u8;
+        let buffer_address = unsafe { NonNull::new_unchecked(self.buffer_address.as_ptr().add(real_starting_slot)) };
+
+        Some((real_first_bit_offset, buffer_address, bit_range.end - bit_range.start))
+    }
+
+    pub(super) fn invalid_subslice_range(bit_start: usize, bit_end: usize, size: usize) -> ! {
+        if bit_start > bit_end {
+            panic!("Invalid bit range start ({}) > end ({})", bit_start, bit_end);
+        }
+
+        panic!("Invalid bit range [{}:{}] for bit map slice of size {}", bit_start, bit_end, size);
+    }
+
     pub(super) fn translate_bit_index(&self, bit_index: usize) -> (usize, usize) {
         if bit_index >= self.size() {
             panic!("Overlow when accessing bit index {}", bit_index);
         }
 
+        self.translate_bit_index_unchecked(bit_index)
+    }
+
+    #[inline(always)]
+    pub(super) fn translate_bit_index_unchecked(&self, bit_index: usize) -> (usize, usize) {
+        debug_assert!(bit_index < self.size());
+
         let real_bit_index = bit_index + (self.first_bit_offset as usize);
         (real_bit_index / B::BIT_COUNT, real_bit_index % B::BIT_COUNT)
     }
 
 }
 
-impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceImpl<'a, B, Mut, O> {
     
     #[inline(always)]
     pub(super) fn modify_bit(&mut self, bit_index: usize, operation: BitmapSliceOperation) {
         let (slot, offset) = self.translate_bit_index(bit_index);
         unsafe {
-            operation.apply(self.buffer_address.as_ptr().add(slot), B::create_bit_mask(offset));
+            operation.apply(self.buffer_address.as_ptr().add(slot), O::create_bit_mask::<B>(offset));
+        }
+    }
+
+    ///
+    /// Writes the low `bit_count` bits of `bits` into this slice starting at `bit_index`,
+    /// leaving every other bit in the touched slots unchanged. The caller must ensure that
+    /// bit_count <= B::BIT_COUNT and the bits are within this slice.
+    /// 
+    pub(super) fn write_bit_chunk(&mut self, bit_index: usize, bit_count: usize, bits: B) {
+        debug_assert!((bit_count <= B::BIT_COUNT) && ((bit_index + bit_count) <= self.bit_count));
+
+        let real_bit_index = bit_index + (self.first_bit_offset as usize);
+        let (slot, offset) = (real_bit_index / B::BIT_COUNT, real_bit_index % B::BIT_COUNT);
+        let buffer = unsafe { self.buffer_address.as_ptr().add(slot) };
+
+        let first_bit_count = core::cmp::min(bit_count, B::BIT_COUNT - offset);
+        let first_mask = O::create_range_mask::<B>(offset, first_bit_count);
+        unsafe {
+            let slot_contents = ptr::read(buffer);
+            ptr::write(buffer, (slot_contents & !first_mask) | (O::shift_left(bits, offset) & first_mask));
+        }
+
+        let remaining_bit_count = bit_count - first_bit_count;
+        if remaining_bit_count != 0 {
+            let second_mask = O::create_range_mask::<B>(0, remaining_bit_count);
+            unsafe {
+                let slot_contents = ptr::read(buffer.add(1));
+                ptr::write(buffer.add(1), (slot_contents & !second_mask) | (O::shift_right(bits, first_bit_count) & second_mask));
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn modify_bit_unchecked(&mut self, bit_index: usize, operation: BitmapSliceOperation) {
+        let (slot, offset) = self.translate_bit_index_unchecked(bit_index);
+        operation.apply(self.buffer_address.as_ptr().add(slot), O::create_bit_mask::<B>(offset));
+    }
+
+    ///
+    /// Rotates the bits in this slice `n` positions towards higher bit indices. The smaller
+    /// of the two pieces that change places is saved to a temporary buffer while the larger
+    /// piece is moved in place. The caller must ensure that 0 < n < self.size().
+    /// 
+    #[cfg(feature = "alloc")]
+    pub(super) fn rotate_bits_left(&mut self, n: usize) {
+        debug_assert!((n > 0) && (n < self.bit_count));
+
+        let bit_count = self.bit_count;
+        let (saved_range, restore_start) = if n <= (bit_count - n) {
+            ((bit_count - n)..bit_count, 0)
+
+        } else {
+            (0..(bit_count - n), n)
+        };
+
+        let saved_bits: Vec<B> = saved_range
+            .clone()
+            .step_by(B::BIT_COUNT)
+            .map(|chunk_start| {
+                self.read_bit_chunk(chunk_start, core::cmp::min(B::BIT_COUNT, saved_range.end - chunk_start))
+            })
+            .collect();
+
+        if restore_start == 0 {
+            self.copy_bits_within(0..(bit_count - n), n);
+
+        } else {
+            self.copy_bits_within((bit_count - n)..bit_count, 0);
+        }
+
+        let saved_bit_count = saved_range.len();
+        for (chunk, bits) in saved_bits.into_iter().enumerate() {
+            let chunk_start = chunk * B::BIT_COUNT;
+            let chunk_bit_count = core::cmp::min(B::BIT_COUNT, saved_bit_count - chunk_start);
+            self.write_bit_chunk(restore_start + chunk_start, chunk_bit_count, bits);
+        }
+    }
+
+    ///
+    /// Reverses the logical bits of this slice by swapping reversed chunks of up to
+    /// `B::BIT_COUNT` bits from both ends towards the middle.
+    /// 
+    pub(super) fn reverse_in_chunks(&mut self) {
+        let (mut front, mut back) = (0, self.bit_count);
+        while (back - front) >= 2 {
+            let chunk_bits = core::cmp::min(B::BIT_COUNT, (back - front) / 2);
+            let unused_bits = B::BIT_COUNT - chunk_bits;
+
+            let front_bits = O::shift_right(self.read_bit_chunk(front, chunk_bits).reverse_bits(), unused_bits);
+            let back_bits = O::shift_right(self.read_bit_chunk(back - chunk_bits, chunk_bits).reverse_bits(), unused_bits);
+            self.write_bit_chunk(front, chunk_bits, back_bits);
+            self.write_bit_chunk(back - chunk_bits, chunk_bits, front_bits);
+
+            front += chunk_bits;
+            back -= chunk_bits;
+        }
+    }
+
+    pub(super) fn toggle_bits_grouped(&mut self, indices: &[usize]) {
+        let mut pending: Option<(usize, B)> = None;
+        for bit_index in indices {
+            let (slot, offset) = self.translate_bit_index(*bit_index);
+            let bit_mask = O::create_bit_mask::<B>(offset);
+            pending = match pending {
+                Some((pending_slot, pending_mask)) if pending_slot == slot => {
+                    Some((slot, pending_mask ^ bit_mask))
+                },
+                Some((pending_slot, pending_mask)) => {
+                    unsafe {
+                        BitmapSliceOperation::Toggle.apply(self.buffer_address.as_ptr().add(pending_slot), pending_mask);
+                    }
+
+                    Some((slot, bit_mask))
+                },
+                None => Some((slot, bit_mask))
+            };
+        }
+
+        if let Some((pending_slot, pending_mask)) = pending {
+            unsafe {
+                BitmapSliceOperation::Toggle.apply(self.buffer_address.as_ptr().add(pending_slot), pending_mask);
+            }
+        }
+    }
+
+    pub(super) fn modify_all(&mut self, operation: BitmapSliceOperation) {
+        let slot_count = self.slot_count();
+        if slot_count == 0 {
+            return;
+        }
+
+        let logical_start = self.first_bit_offset as usize;
+        let real_range = logical_start..(logical_start + self.bit_count);
+        let first_slot_partial = (real_range.start % B::BIT_COUNT) != 0;
+        let last_slot_partial = (real_range.end % B::BIT_COUNT) != 0;
+        let buffer = self.buffer_address.as_ptr();
+        for current_slot in 0..slot_count {
+            let is_partial =
+                ((current_slot == 0) && first_slot_partial) ||
+                (((current_slot + 1) == slot_count) && last_slot_partial);
+
+            unsafe {
+                if is_partial {
+                    operation.apply(buffer.add(current_slot), Self::slot_mask(current_slot, &real_range));
+
+                } else {
+                    operation.apply_full(buffer.add(current_slot));
+                }
+            }
+        }
+    }
+
+    pub(super) fn clear_outside_range(&mut self, range: Range<usize>) {
+        if range.start > range.end {
+            panic!("Invalid bit range start ({}) > end ({})", range.start, range.end);
+
+        } else if range.end > self.size() {
+            panic!("Invalid bit range [{}:{}] for bitmap of size {}", range.start, range.end, self.size());
+        }
+
+        let logical_start = self.first_bit_offset as usize;
+        let real_range = logical_start..(logical_start + self.bit_count);
+        let real_keep_range = (logical_start + range.start)..(logical_start + range.end);
+        let buffer = self.buffer_address.as_ptr();
+        for current_slot in 0..self.slot_count() {
+            let slot_start = current_slot * B::BIT_COUNT;
+            let slot_end = slot_start + B::BIT_COUNT;
+            if (real_keep_range.start <= slot_start) && (slot_end <= real_keep_range.end) {
+                continue;
+            }
+
+            let mut clear_mask = Self::slot_mask(current_slot, &real_range);
+            if (real_keep_range.start < slot_end) && (slot_start < real_keep_range.end) {
+                clear_mask &= !Self::slot_mask(current_slot, &real_keep_range);
+            }
+
+            unsafe { BitmapSliceOperation::Clear.apply(buffer.add(current_slot), clear_mask) };
         }
     }
 
@@ -145,7 +694,7 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
 
         let mut current_offset = starting_offset;
         let mut current_count = B::BIT_COUNT - current_offset;
-        let mut current_mask = B::create_range_mask(current_offset, current_count);
+        let mut current_mask = O::create_range_mask::<B>(current_offset, current_count);
         let mut remaining = bit_range.count();
         while remaining >= current_count {
             unsafe { operation.apply(buffer, current_mask) };
@@ -158,7 +707,7 @@ impl<'a, B: BitStore> BitmapSliceImpl<'a, B, Mut> {
         }
 
         if remaining != 0 {
-            unsafe { operation.apply(buffer, B::create_range_mask(current_offset, remaining)); }
+            unsafe { operation.apply(buffer, O::create_range_mask::<B>(current_offset, remaining)); }
         }
     }
 
@@ -1,27 +1,180 @@
 
 use super::BitmapSliceImpl;
 
-use crate::polyfill::Const;
-use crate::store::BitStore;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use crate::polyfill::{Const, Mut};
+use crate::store::{BitOrder, BitStore, Lsb0};
 use crate::traits::BitmapOpts;
 
 ///
 /// An iterator over each set bit in a bitmap slice.
 /// 
-pub struct BitmapSliceIter<'a, B: BitStore> {
-    inner: BitmapSliceRangeIter<'a, B>,
+pub struct BitmapSliceIter<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapSliceRangeIter<'a, B, O>,
+    last_range: Option<(usize, usize)>,
+    last_back_range: Option<(usize, usize)>,
+    remaining: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceIter<'a, B, O> {
+    
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const, O>) -> Self {
+        let remaining = inner.count_ones();
+        BitmapSliceIter { inner: BitmapSliceRangeIter::new(inner), last_range: None, last_back_range: None, remaining }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapSliceIter<'a, B, O> {
+
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.last_range.is_none() {
+            self.last_range = self.inner.next().or_else(|| self.last_back_range.take());
+        }
+
+        if let Some((range_start, range_count)) = self.last_range.as_mut() {
+            debug_assert!(*range_count != 0);
+
+            let result = *range_start;
+            *range_start += 1;
+            *range_count -= 1;
+            if *range_count == 0 {
+                self.last_range.take();
+            }
+
+            self.remaining -= 1;
+            Some(result)
+
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> DoubleEndedIterator for BitmapSliceIter<'a, B, O> {
+
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.last_back_range.is_none() {
+            self.last_back_range = self.inner.next_back().or_else(|| self.last_range.take());
+        }
+
+        if let Some((range_start, range_count)) = self.last_back_range.as_mut() {
+            debug_assert!(*range_count != 0);
+
+            *range_count -= 1;
+            let result = *range_start + *range_count;
+            if *range_count == 0 {
+                self.last_back_range.take();
+            }
+
+            self.remaining -= 1;
+            Some(result)
+
+        } else {
+            None
+        }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> ExactSizeIterator for BitmapSliceIter<'a, B, O> { }
+
+///
+/// An iterator over each range of set bits in a bitmap slice.
+/// 
+pub struct BitmapSliceRangeIter<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapSliceImpl<'a, B, Const, O>,
+    last_range_end: usize,
+    last_back_range_start: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceRangeIter<'a, B, O> {
+    
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const, O>) -> Self {
+        BitmapSliceRangeIter { last_back_range_start: inner.size(), inner, last_range_end: 0 }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapSliceRangeIter<'a, B, O> {
+
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let search_range = self.last_range_end..self.last_back_range_start;
+        if let Some(range_start) = self.inner.find_next_set_in_range(search_range.clone()) {
+            let range_end =
+                self.inner.find_next_clear_in_range((range_start + 1)..search_range.end)
+                    .unwrap_or(search_range.end);
+
+            self.last_range_end = range_end;
+            Some((range_start, range_end - range_start))
+
+        } else {
+            self.last_range_end = self.last_back_range_start;
+            None
+        }
+    }
+
+    ///
+    /// The upper bound is derived from the number of bits not yet visited from either end.
+    /// Distinct set ranges must be separated by at least one clear bit, so `n` remaining bits
+    /// can hold at most `(n + 1) / 2` ranges. The lower bound is zero as the remaining bits
+    /// may all be clear.
+    /// 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_bits = self.last_back_range_start - self.last_range_end;
+        (0, Some(crate::polyfill::div_ceil(remaining_bits, 2)))
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> DoubleEndedIterator for BitmapSliceRangeIter<'a, B, O> {
+
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let search_range = self.last_range_end..self.last_back_range_start;
+        if let Some(last_set_bit) = self.inner.find_prev_in_range::<false>(search_range.clone()) {
+            let range_start =
+                self.inner.find_prev_in_range::<true>(search_range.start..last_set_bit)
+                    .map_or(search_range.start, |last_clear_bit| last_clear_bit + 1);
+
+            self.last_back_range_start = range_start;
+            Some((range_start, last_set_bit + 1 - range_start))
+
+        } else {
+            self.last_back_range_start = self.last_range_end;
+            None
+        }
+    }
+
+}
+
+///
+/// An iterator over each clear bit in a bitmap slice.
+/// 
+pub struct BitmapClearIter<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapClearRangeIter<'a, B, O>,
     last_range: Option<(usize, usize)>
 }
 
-impl<'a, B: BitStore> BitmapSliceIter<'a, B> {
+impl<'a, B: BitStore, O: BitOrder> BitmapClearIter<'a, B, O> {
     
-    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const>) -> Self {
-        BitmapSliceIter { inner: BitmapSliceRangeIter::new(inner), last_range: None }
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const, O>) -> Self {
+        BitmapClearIter { inner: BitmapClearRangeIter::new(inner), last_range: None }
     }
 
 }
 
-impl<'a, B: BitStore> Iterator for BitmapSliceIter<'a, B> {
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapClearIter<'a, B, O> {
 
     type Item = usize;
 
@@ -50,28 +203,28 @@ impl<'a, B: BitStore> Iterator for BitmapSliceIter<'a, B> {
 }
 
 ///
-/// An iterator over each range of set bits in a bitmap slice.
+/// An iterator over each range of clear bits in a bitmap slice.
 /// 
-pub struct BitmapSliceRangeIter<'a, B: BitStore> {
-    inner: BitmapSliceImpl<'a, B, Const>,
+pub struct BitmapClearRangeIter<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapSliceImpl<'a, B, Const, O>,
     last_range_end: usize
 }
 
-impl<'a, B: BitStore> BitmapSliceRangeIter<'a, B> {
+impl<'a, B: BitStore, O: BitOrder> BitmapClearRangeIter<'a, B, O> {
     
-    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const>) -> Self {
-        BitmapSliceRangeIter { inner, last_range_end: 0 }
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const, O>) -> Self {
+        BitmapClearRangeIter { inner, last_range_end: 0 }
     }
 
 }
 
-impl<'a, B: BitStore> Iterator for BitmapSliceRangeIter<'a, B> {
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapClearRangeIter<'a, B, O> {
 
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.last_range_end < self.inner.size() {
-            if let Some(next_range) = self.inner.find_next_set_range_from(self.last_range_end) {
+            if let Some(next_range) = self.inner.find_next_clear_range_from(self.last_range_end) {
                 self.last_range_end = next_range.0 + next_range.1;
                 Some(next_range)
 
@@ -86,3 +239,275 @@ impl<'a, B: BitStore> Iterator for BitmapSliceRangeIter<'a, B> {
     }
 
 }
+
+///
+/// An iterator over each set bit in a bitmap slice, from the highest index to the lowest.
+/// 
+pub struct BitmapSliceRevIter<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapSliceImpl<'a, B, Const, O>,
+    last_range: Option<(usize, usize)>,
+    last_range_start: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceRevIter<'a, B, O> {
+    
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const, O>) -> Self {
+        BitmapSliceRevIter { last_range_start: inner.size(), inner, last_range: None }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapSliceRevIter<'a, B, O> {
+
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.last_range.is_none() {
+            if let Some(last_set_bit) = self.inner.find_prev_in_range::<false>(0..self.last_range_start) {
+                let range_start =
+                    self.inner.find_prev_in_range::<true>(0..last_set_bit)
+                        .map_or(0, |last_clear_bit| last_clear_bit + 1);
+
+                self.last_range = Some((range_start, last_set_bit + 1));
+                self.last_range_start = range_start;
+            }
+        }
+
+        if let Some((range_start, range_end)) = self.last_range.as_mut() {
+            debug_assert!(*range_start < *range_end);
+
+            *range_end -= 1;
+            let result = *range_end;
+            if *range_start == *range_end {
+                self.last_range.take();
+            }
+
+            Some(result)
+
+        } else {
+            None
+        }
+    }
+
+}
+
+///
+/// An iterator over each backing word covered by a bitmap slice, including words
+/// that do not contain any set bits. Bits outside of the slice are masked off.
+/// 
+pub struct BitmapSliceWordIter<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapSliceImpl<'a, B, Const, O>,
+    next_slot: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceWordIter<'a, B, O> {
+    
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const, O>) -> Self {
+        BitmapSliceWordIter { inner, next_slot: 0 }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapSliceWordIter<'a, B, O> {
+
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_slot < self.inner.slot_count() {
+            let result = self.inner.read_masked_slot(self.next_slot);
+            self.next_slot += 1;
+            Some(result)
+
+        } else {
+            None
+        }
+    }
+
+}
+
+///
+/// An iterator over consecutive non-overlapping subslices of a bitmap slice. Every subslice
+/// has the same size, except for the last subslice which may be shorter.
+/// 
+pub struct BitmapChunks<'a, B: BitStore, O: BitOrder = Lsb0> {
+    remaining: BitmapSliceImpl<'a, B, Const, O>,
+    chunk_bits: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapChunks<'a, B, O> {
+
+    pub(crate) fn new(remaining: BitmapSliceImpl<'a, B, Const, O>, chunk_bits: usize) -> Self {
+        BitmapChunks { remaining, chunk_bits }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapChunks<'a, B, O> {
+
+    type Item = BitmapSliceImpl<'a, B, Const, O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.size() == 0 {
+            None
+
+        } else {
+            let chunk_bits = self.chunk_bits.min(self.remaining.size());
+            let (chunk, remaining) = self.remaining.split_at(chunk_bits);
+            self.remaining = remaining;
+            Some(chunk)
+        }
+    }
+
+}
+
+///
+/// An iterator over overlapping subslices of a bitmap slice. Every subslice has the same
+/// size and starts one bit after the previous subslice.
+/// 
+pub struct BitmapWindows<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapSliceImpl<'a, B, Const, O>,
+    next_start: usize,
+    window_bits: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapWindows<'a, B, O> {
+
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Const, O>, window_bits: usize) -> Self {
+        BitmapWindows { inner, next_start: 0, window_bits }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapWindows<'a, B, O> {
+
+    type Item = BitmapSliceImpl<'a, B, Const, O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window_end = self.next_start + self.window_bits;
+        if window_end > self.inner.size() {
+            None
+
+        } else {
+            let (_, window) = self.inner.split_at(self.next_start);
+            let (window, _) = window.split_at(self.window_bits);
+            self.next_start += 1;
+            Some(window)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.inner.size() + 1).saturating_sub(self.next_start + self.window_bits);
+        (remaining, Some(remaining))
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> ExactSizeIterator for BitmapWindows<'a, B, O> { }
+
+///
+/// An iterator over consecutive non-overlapping mutable subslices of a bitmap slice. Every
+/// subslice has the same size, except for the last subslice which may be shorter.
+/// 
+pub struct BitmapChunksMut<'a, B: BitStore, O: BitOrder = Lsb0> {
+    remaining: Option<BitmapSliceImpl<'a, B, Mut, O>>,
+    chunk_bits: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapChunksMut<'a, B, O> {
+
+    pub(crate) fn new(remaining: BitmapSliceImpl<'a, B, Mut, O>, chunk_bits: usize) -> Self {
+        BitmapChunksMut { remaining: Some(remaining), chunk_bits }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapChunksMut<'a, B, O> {
+
+    type Item = BitmapSliceImpl<'a, B, Mut, O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining.take().filter(|remaining| remaining.size() != 0)?;
+        let chunk_bits = self.chunk_bits.min(remaining.size());
+        let (chunk, remaining) = remaining.split_at_mut(chunk_bits);
+        self.remaining = Some(remaining);
+        Some(chunk)
+    }
+
+}
+
+///
+/// A proxy for a single bit of a mutable bitmap slice, yielded by
+/// [BitmapSliceImpl::iter_mut]. The bit can be read and written through the proxy.
+/// 
+pub struct BitRef<'a, B: BitStore> {
+    target: NonNull<B>,
+    mask: B,
+    _lt: PhantomData<&'a mut B>
+}
+
+impl<'a, B: BitStore> BitRef<'a, B> {
+
+    ///
+    /// This routine returns the current value of the referenced bit.
+    /// 
+    pub fn get(&self) -> bool {
+        let slot_contents = unsafe { self.target.as_ptr().read() };
+        (slot_contents & self.mask) != B::ZERO
+    }
+
+    ///
+    /// This routine sets the referenced bit to `value`.
+    /// 
+    pub fn set(&mut self, value: bool) {
+        unsafe {
+            let slot_contents = self.target.as_ptr().read();
+            let slot_contents = if value { slot_contents | self.mask } else { slot_contents & !self.mask };
+            self.target.as_ptr().write(slot_contents);
+        }
+    }
+
+}
+
+///
+/// An iterator over every bit of a mutable bitmap slice, yielding a [BitRef] proxy for
+/// each bit position in ascending order.
+/// 
+pub struct BitmapSliceIterMut<'a, B: BitStore, O: BitOrder = Lsb0> {
+    inner: BitmapSliceImpl<'a, B, Mut, O>,
+    next_bit: usize
+}
+
+impl<'a, B: BitStore, O: BitOrder> BitmapSliceIterMut<'a, B, O> {
+
+    pub(crate) fn new(inner: BitmapSliceImpl<'a, B, Mut, O>) -> Self {
+        BitmapSliceIterMut { inner, next_bit: 0 }
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> Iterator for BitmapSliceIterMut<'a, B, O> {
+
+    type Item = BitRef<'a, B>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_bit >= self.inner.size() {
+            return None;
+        }
+
+        let (slot, offset) = self.inner.translate_bit_index_unchecked(self.next_bit);
+        self.next_bit += 1;
+
+        // Every proxy refers to a distinct bit and only accesses its slot through a raw
+        // pointer read and write, so proxies sharing a slot never hold a reference to it.
+        let target = unsafe { NonNull::new_unchecked(self.inner.buffer_address.as_ptr().add(slot)) };
+        Some(BitRef { target, mask: O::create_bit_mask::<B>(offset), _lt: PhantomData })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.inner.size() - self.next_bit;
+        (remaining, Some(remaining))
+    }
+
+}
+
+impl<'a, B: BitStore, O: BitOrder> ExactSizeIterator for BitmapSliceIterMut<'a, B, O> { }
@@ -1,6 +1,9 @@
 
 use super::*;
-use crate::traits::{BitmapOpts, BitmapOptsMut};
+use crate::store::{BitOrder, BitStore, Lsb0, Msb0};
+use crate::traits::{BitmapOpts, BitmapOptsAlloc, BitmapOptsMut};
+
+use std::ops::Range;
 
 #[test]
 fn test_clear_bit_range() {
@@ -16,6 +19,40 @@ fn test_clear_bit_range() {
     assert_eq!(buffer, [0b00000000, 0b00000000, 0b00000000]);
 }
 
+#[test]
+fn test_new_range_validation() {
+    let mut buffer = [0u8; 8];
+
+    assert_eq!(BitmapSlice::new(&buffer, 60..64).size(), 4);
+    assert_eq!(BitmapSliceMut::new(&mut buffer, 57..63).size(), 6);
+    assert_eq!(BitmapSlice::new(&buffer, 64..64).size(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid bit range [0:9] for buffer of size 8")]
+fn test_new_range_past_end() {
+    let buffer = [0u8; 8];
+    BitmapSlice::new(&buffer, 3..65);
+}
+
+#[test]
+fn test_find_next_in_range_bounds() {
+    let buffer = [0b00101000u8, 0b00000001];
+    let slice = BitmapSlice::new(&buffer, 3..16);
+
+    assert_eq!(slice.find_next_set_in_range(0..2), Some(0));
+    assert_eq!(slice.find_next_set_in_range(1..2), None);
+    assert_eq!(slice.find_next_set_in_range(1..3), Some(2));
+    assert_eq!(slice.find_next_clear_in_range(1..2), Some(1));
+}
+
+#[test]
+#[should_panic(expected = "Invalid bit range [10:14] for bitmap of size 13")]
+fn test_find_next_in_range_past_end() {
+    let buffer = [0u8; 2];
+    BitmapSlice::new(&buffer, 3..16).find_next_set_in_range(10..14);
+}
+
 #[test]
 fn test_find_next_clear_range() {
     let buffer = [0b11110000u8, 0b11111111, 0b00001111];
@@ -71,3 +108,952 @@ fn test_toggle_bit() {
     BitmapSliceMut::new(&mut buffer, 0..24).toggle_bit_range(0..24);
     assert_eq!(buffer, [0b10100101, 0b00001111, 0b11000011]);
 }
+
+#[test]
+fn test_word_iter_all() {
+    let buffer = [0b11110000u8, 0b00000000, 0b11111111];
+
+    let words: Vec<u8> = BitmapSlice::new(&buffer, 0..24).word_iter_all().collect();
+    assert_eq!(words, [0b11110000, 0b00000000, 0b11111111]);
+
+    let words: Vec<u8> = BitmapSlice::new(&buffer, 6..19).word_iter_all().collect();
+    assert_eq!(words, [0b11000000, 0b00000000, 0b00000111]);
+}
+
+#[test]
+fn test_count_ones() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001];
+
+    assert_eq!(BitmapSlice::new(&buffer, 0..24).count_ones(), 16);
+    assert_eq!(BitmapSlice::new(&buffer, 0..24).count_zeros(), 8);
+    assert_eq!(BitmapSlice::new(&buffer, 3..6).count_ones(), 2);
+    assert_eq!(BitmapSlice::new(&buffer, 3..6).count_zeros(), 1);
+    assert_eq!(BitmapSlice::new(&buffer, 5..21).count_ones(), 12);
+    assert_eq!(BitmapSlice::new(&buffer, 5..21).count_zeros(), 4);
+    assert_eq!(BitmapSlice::new(&buffer, 9..9).count_ones(), 0);
+    assert_eq!(BitmapSlice::new(&buffer, 9..9).count_zeros(), 0);
+}
+
+#[test]
+fn test_parity() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001];
+
+    assert!(!BitmapSlice::new(&buffer, 0..24).parity());
+    assert!(BitmapSlice::new(&buffer, 1..16).parity());
+    assert!(!BitmapSlice::new(&buffer, 1..17).parity());
+    assert!(!BitmapSlice::new(&buffer, 3..6).parity());
+    assert!(BitmapSlice::new(&buffer, 3..5).parity());
+}
+
+#[test]
+fn test_count_ones_in_range() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001];
+    let slice = BitmapSlice::new(&buffer, 2..23);
+
+    assert_eq!(slice.count_ones_in_range(0..21), 14);
+    assert_eq!(slice.count_zeros_in_range(0..21), 7);
+    assert_eq!(slice.count_ones_in_range(1..4), 2);
+    assert_eq!(slice.count_zeros_in_range(1..4), 1);
+    assert_eq!(slice.count_ones_in_range(3..15), 11);
+    assert_eq!(slice.count_zeros_in_range(3..15), 1);
+    assert_eq!(slice.count_ones_in_range(14..19), 2);
+    assert_eq!(slice.count_ones_in_range(7..7), 0);
+    assert_eq!(slice.count_zeros_in_range(7..7), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_count_ones_in_range_invalid() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001];
+    BitmapSlice::new(&buffer, 2..23).count_ones_in_range(4..22);
+}
+
+#[test]
+fn test_find_next_set_in_range_offset() {
+    let buffer = [0b11000000u8];
+    let slice = BitmapSlice::new(&buffer, 3..6);
+
+    assert_eq!(slice.find_first_set(), None);
+    assert_eq!(slice.find_first_clear_range(), Some((0, 3)));
+}
+
+#[test]
+fn test_iter_rev() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001];
+
+    for (start, end) in [(0, 24), (3, 21), (7, 9), (9, 16), (12, 12)] {
+        let slice = BitmapSlice::new(&buffer, start..end);
+        let mut forward: Vec<usize> = slice.iter().collect();
+        forward.reverse();
+
+        assert_eq!(slice.iter_rev().collect::<Vec<_>>(), forward);
+    }
+}
+
+#[test]
+fn test_iter_exact_size() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001, 0b10000001];
+
+    for (start, end) in [(0, 32), (3, 29), (7, 17), (12, 12)] {
+        let slice = BitmapSlice::new(&buffer, start..end);
+        let mut iter = slice.iter();
+        let mut expected = slice.count_ones();
+        assert_eq!(iter.len(), expected);
+
+        while iter.next().is_some() {
+            expected -= 1;
+            assert_eq!(iter.len(), expected);
+            assert_eq!(iter.size_hint(), (expected, Some(expected)));
+
+            if iter.next_back().is_some() {
+                expected -= 1;
+                assert_eq!(iter.len(), expected);
+            }
+        }
+
+        assert_eq!(iter.len(), 0);
+    }
+}
+
+#[test]
+fn test_iter_double_ended() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001, 0b10000001];
+
+    for (start, end) in [(0, 32), (3, 29), (7, 17), (12, 12)] {
+        let slice = BitmapSlice::new(&buffer, start..end);
+        let forward: Vec<usize> = slice.iter().collect();
+        let mut backward: Vec<usize> = slice.iter().rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        let forward_ranges: Vec<(usize, usize)> = slice.range_iter().collect();
+        let mut backward_ranges: Vec<(usize, usize)> = slice.range_iter().rev().collect();
+        backward_ranges.reverse();
+        assert_eq!(forward_ranges, backward_ranges);
+    }
+
+    let slice = BitmapSlice::new(&buffer, 3..29);
+    let mut range_iter = slice.range_iter();
+    assert_eq!(range_iter.next(), Some((1, 2)));
+    assert_eq!(range_iter.next_back(), Some((21, 1)));
+    assert_eq!(range_iter.next_back(), Some((17, 1)));
+    assert_eq!(range_iter.next(), Some((4, 10)));
+    assert_eq!(range_iter.next_back(), None);
+    assert_eq!(range_iter.next(), None);
+
+    let mut iter = slice.iter();
+    assert_eq!(iter.next_back(), Some(21));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next_back(), Some(17));
+    assert_eq!(iter.next_back(), Some(13));
+    assert_eq!(iter.by_ref().count(), 8);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_clear_iter() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001];
+    let slice = BitmapSlice::new(&buffer, 2..23);
+
+    assert_eq!(slice.clear_iter().collect::<Vec<_>>(), [1, 4, 15, 16, 17, 19, 20]);
+    assert_eq!(slice.clear_range_iter().collect::<Vec<_>>(), [(1, 1), (4, 1), (15, 3), (19, 2)]);
+    assert_eq!(BitmapSlice::new(&buffer, 8..16).clear_range_iter().next(), None);
+}
+
+#[test]
+fn test_invert() {
+    let mut buffer = [0b10101010u8, 0b11110000, 0b00000000];
+
+    BitmapSliceMut::new(&mut buffer, 3..21).invert();
+    assert_eq!(buffer, [0b01010010, 0b00001111, 0b00011111]);
+
+    BitmapSliceMut::new(&mut buffer, 8..16).invert();
+    assert_eq!(buffer, [0b01010010, 0b11110000, 0b00011111]);
+
+    BitmapSliceMut::new(&mut buffer, 9..12).invert();
+    assert_eq!(buffer, [0b01010010, 0b11111110, 0b00011111]);
+
+    BitmapSliceMut::new(&mut buffer, 0..24).invert();
+    assert_eq!(buffer, [0b10101101, 0b00000001, 0b11100000]);
+}
+
+#[test]
+fn test_find_prev() {
+    let buffer = [0b00001111u8, 0b00000000, 0b11110000];
+
+    assert_eq!(BitmapSlice::new(&buffer, 0..24).find_last_set(), Some(23));
+    assert_eq!(BitmapSlice::new(&buffer, 0..24).find_last_clear(), Some(19));
+    assert_eq!(BitmapSlice::new(&buffer, 2..20).find_last_set(), Some(1));
+    assert_eq!(BitmapSlice::new(&buffer, 2..21).find_last_set(), Some(18));
+    assert_eq!(BitmapSlice::new(&buffer, 4..20).find_last_set(), None);
+    assert_eq!(BitmapSlice::new(&buffer, 1..4).find_last_clear(), None);
+    assert_eq!(BitmapSlice::new(&buffer, 3..21).find_prev_set_from(16), Some(0));
+    assert_eq!(BitmapSlice::new(&buffer, 3..21).find_prev_set_from(100), Some(17));
+    assert_eq!(BitmapSlice::new(&buffer, 3..21).find_prev_clear_from(0), None);
+    assert_eq!(BitmapSlice::new(&buffer, 3..21).find_prev_clear_from(9), Some(9));
+    assert_eq!(BitmapSlice::new(&buffer, 20..24).find_prev_clear_from(3), None);
+}
+
+#[test]
+fn test_is_empty_is_full() {
+    let buffer = [0b11110000u8, 0xFF, 0b00000111];
+
+    assert!(BitmapSlice::new(&buffer, 4..19).is_full());
+    assert!(BitmapSlice::new(&buffer, 4..19).all_set());
+    assert!(!BitmapSlice::new(&buffer, 3..19).is_full());
+    assert!(!BitmapSlice::new(&buffer, 4..20).is_full());
+    assert!(!BitmapSlice::new(&buffer, 4..19).is_empty());
+
+    assert!(BitmapSlice::new(&buffer, 1..4).is_empty());
+    assert!(!BitmapSlice::new(&buffer, 1..4).any_set());
+    assert!(BitmapSlice::new(&buffer, 19..24).is_empty());
+    assert!(!BitmapSlice::new(&buffer, 18..24).is_empty());
+    assert!(BitmapSlice::new(&buffer, 18..24).any_set());
+
+    assert!(BitmapSlice::new(&buffer, 5..5).is_empty());
+    assert!(BitmapSlice::new(&buffer, 5..5).is_full());
+}
+
+#[test]
+fn test_format() {
+    let buffer = [0b10110000u8, 0b00000001, 0xFF, 0xFF];
+
+    let slice = BitmapSlice::new(&buffer, 4..17);
+    assert_eq!(format!("{}", slice), "1101100000001");
+    assert_eq!(format!("{:?}", slice), "BitmapSlice { size: 13, bits: 11011000 00001 }");
+    assert_eq!(format!("{}", BitmapSlice::new(&buffer, 3..3)), "");
+
+    let large_buffer = [0u64; 8];
+    let debug_output = format!("{:?}", BitmapSlice::new(&large_buffer, 1..500));
+    assert!(debug_output.starts_with("BitmapSlice { size: 499, bits: 00000000 "));
+    assert!(debug_output.ends_with(" 00000000 ... }"));
+    assert_eq!(debug_output.matches('0').count(), 256);
+}
+
+#[test]
+fn test_copy_bits_from() {
+    let source = [0xDEADBEEFu32, 0x12345678, 0xCAFEF00D];
+    let source_slice = BitmapSlice::new(&source, 0..96);
+
+    let mut aligned = [0u32; 3];
+    BitmapSliceMut::new(&mut aligned, 0..96).copy_bits_from(32, &source_slice, 0..64);
+    assert_eq!(aligned, [0, 0xDEADBEEF, 0x12345678]);
+
+    let mut misaligned = [0u32; 3];
+    BitmapSliceMut::new(&mut misaligned, 3..90).copy_bits_from(2, &source_slice, 7..77);
+    let misaligned_slice = BitmapSlice::new(&misaligned, 0..96);
+    for bit_index in 0..96 {
+        let expected = (5..75).contains(&bit_index) && source_slice.get_bit(bit_index + 2);
+        assert_eq!(misaligned_slice.get_bit(bit_index), expected);
+    }
+
+    let mut bytes = [0xFFu8; 2];
+    BitmapSliceMut::new(&mut bytes, 0..16).copy_bits_from(3, &BitmapSlice::new(&[0u8], 0..8), 1..6);
+    assert_eq!(bytes, [0b00000111, 0xFF]);
+}
+
+#[test]
+fn test_copy_bits_within() {
+    let original = [0x0123456789ABCDEFu64, 0xFEDCBA9876543210];
+    let original_slice = BitmapSlice::new(&original, 0..128);
+
+    let mut forward = original;
+    BitmapSliceMut::new(&mut forward, 0..128).copy_bits_within(13..113, 5);
+    let forward_slice = BitmapSlice::new(&forward, 0..128);
+    for bit_index in 0..128 {
+        let expected = if (5..105).contains(&bit_index) {
+            original_slice.get_bit(bit_index + 8)
+
+        } else {
+            original_slice.get_bit(bit_index)
+        };
+
+        assert_eq!(forward_slice.get_bit(bit_index), expected);
+    }
+
+    let mut backward = original;
+    BitmapSliceMut::new(&mut backward, 1..128).copy_bits_within(4..104, 21);
+    let backward_slice = BitmapSlice::new(&backward, 0..128);
+    for bit_index in 0..128 {
+        let expected = if (22..122).contains(&bit_index) {
+            original_slice.get_bit(bit_index - 17)
+
+        } else {
+            original_slice.get_bit(bit_index)
+        };
+
+        assert_eq!(backward_slice.get_bit(bit_index), expected);
+    }
+}
+
+#[test]
+fn test_windowed_ones() {
+    let buffer = [0b11010110u8, 0b00111001, 0b10000001];
+    let slice = BitmapSlice::new(&buffer, 3..22);
+
+    for window in [0, 1, 5, 8, 19] {
+        let mut out = vec![0; slice.size() - window + 1];
+        slice.windowed_ones(window, &mut out);
+
+        for (window_start, count) in out.iter().enumerate() {
+            assert_eq!(*count, slice.count_ones_in_range(window_start..(window_start + window)));
+        }
+    }
+}
+
+#[test]
+fn test_rank() {
+    let buffer = [0b10110110u8, 0b11111111, 0b00000101];
+    let slice = BitmapSlice::new(&buffer, 2..19);
+
+    assert_eq!(slice.rank(0), 0);
+    assert_eq!(slice.rank(1), 1);
+    assert_eq!(slice.rank(6), 4);
+    assert_eq!(slice.rank(slice.size()), slice.count_ones());
+    assert_eq!(slice.rank(slice.size()), 14);
+}
+
+#[test]
+#[should_panic]
+fn test_rank_invalid() {
+    let buffer = [0u8; 2];
+    BitmapSlice::new(&buffer, 1..9).rank(9);
+}
+
+#[test]
+fn test_select() {
+    let buffer = [0b10110111u16, 0b0000000000000000, 0b1000000000000011];
+    let slice = BitmapSlice::new(&buffer, 2..47);
+
+    assert_eq!(slice.select(0), slice.find_first_set());
+    assert_eq!(slice.select(0), Some(0));
+    assert_eq!(slice.select(1), Some(2));
+    assert_eq!(slice.select(3), Some(5));
+    assert_eq!(slice.select(4), Some(30));
+    assert_eq!(slice.select(5), Some(31));
+    assert_eq!(slice.select(6), None);
+
+    for n in 0..slice.count_ones() {
+        assert_eq!(slice.rank(slice.select(n).unwrap()), n);
+    }
+
+    assert_eq!(BitmapSlice::new(&buffer, 3..3).select(0), None);
+}
+
+#[test]
+fn test_toggle_bits() {
+    let mut buffer = [0b00001111u8, 0x00, 0xFF];
+
+    BitmapSliceMut::new(&mut buffer, 0..24).toggle_bits(&[3, 3, 5]);
+    assert_eq!(buffer, [0b00101111, 0x00, 0xFF]);
+
+    BitmapSliceMut::new(&mut buffer, 2..24).toggle_bits(&[0, 1, 6, 7, 20, 13, 7]);
+    assert_eq!(buffer, [0b00100011, 0b10000001, 0b10111111]);
+
+    BitmapSliceMut::new(&mut buffer, 0..24).toggle_bits(&[]);
+    assert_eq!(buffer, [0b00100011, 0b10000001, 0b10111111]);
+}
+
+#[test]
+fn test_hamming_distance() {
+    let lhs = [0xF0F0F0F0u32, 0x0000FFFF];
+    let rhs = [0x0FF0F0F0u32, 0xFFFF0000];
+
+    assert_eq!(BitmapSlice::new(&lhs, 0..64).hamming_distance(&BitmapSlice::new(&rhs, 0..64)), 40);
+    assert_eq!(BitmapSlice::new(&lhs, 0..48).hamming_distance(&BitmapSlice::new(&rhs, 0..48)), 24);
+    assert_eq!(BitmapSlice::new(&lhs, 4..36).hamming_distance(&BitmapSlice::new(&rhs, 4..36)), 12);
+    assert_eq!(BitmapSlice::new(&lhs, 0..8).hamming_distance(&BitmapSlice::new(&lhs, 4..12)), 8);
+    assert_eq!(BitmapSlice::new(&lhs, 0..0).hamming_distance(&BitmapSlice::new(&rhs, 5..5)), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_hamming_distance_invalid() {
+    let buffer = [0u8; 2];
+    BitmapSlice::new(&buffer, 0..8).hamming_distance(&BitmapSlice::new(&buffer, 0..9));
+}
+
+#[test]
+fn test_find_next_matches_scalar() {
+    let mut buffer = [0u8; 67];
+    for set_index in [5, 63, 64, 200, 201, 402, 530] {
+        buffer[set_index / 8] |= 1 << (set_index % 8);
+    }
+
+    let mut inverted_buffer = buffer;
+    for byte in inverted_buffer.iter_mut() {
+        *byte = !*byte;
+    }
+
+    let bool_buffer: Vec<bool> = (0..536).map(|bit_index| (buffer[bit_index / 8] & (1 << (bit_index % 8))) != 0).collect();
+
+    for starting_bit in [0, 1, 7, 9, 60, 65, 203] {
+        let slice = BitmapSlice::new(&buffer, starting_bit..536);
+        let inverted_slice = BitmapSlice::new(&inverted_buffer, starting_bit..536);
+        let bool_slice = BitmapSlice::new(&bool_buffer, starting_bit..536);
+
+        for search_start in 0..slice.size() {
+            let expected = (search_start..slice.size()).find(|bit_index| slice.get_bit(*bit_index));
+            assert_eq!(slice.find_next_set_from(search_start), expected);
+            assert_eq!(inverted_slice.find_next_clear_from(search_start), expected);
+            assert_eq!(bool_slice.find_next_set_from(search_start), expected);
+        }
+    }
+}
+
+#[test]
+fn test_unchecked_access() {
+    let mut buffer = [0u8; 3];
+    let mut slice = BitmapSliceMut::new(&mut buffer, 5..21);
+
+    unsafe {
+        slice.set_bit_unchecked(0);
+        slice.set_bit_unchecked(15);
+        slice.toggle_bit_unchecked(4);
+        slice.toggle_bit_unchecked(15);
+        slice.set_bit_unchecked(8);
+        slice.clear_bit_unchecked(8);
+
+        assert!(slice.get_bit_unchecked(0));
+        assert!(slice.get_bit_unchecked(4));
+        assert!(!slice.get_bit_unchecked(8));
+        assert!(!slice.get_bit_unchecked(15));
+    }
+
+    assert_eq!(buffer, [0b0010_0000, 0b0000_0010, 0b0000_0000]);
+}
+
+#[test]
+fn test_set_all_clear_all() {
+    let mut buffer = [0u8; 4];
+    BitmapSliceMut::new(&mut buffer, 3..29).set_all();
+    assert_eq!(buffer, [0b1111_1000, 0xFF, 0xFF, 0b0001_1111]);
+
+    BitmapSliceMut::new(&mut buffer, 5..12).clear_all();
+    assert_eq!(buffer, [0b0001_1000, 0b1111_0000, 0xFF, 0b0001_1111]);
+
+    BitmapSliceMut::new(&mut buffer, 17..18).clear_all();
+    BitmapSliceMut::new(&mut buffer, 0..0).set_all();
+    assert_eq!(buffer, [0b0001_1000, 0b1111_0000, 0b1111_1101, 0b0001_1111]);
+}
+
+#[test]
+fn test_shift() {
+    let original = [0x0123456789ABCDEFu64, 0xFEDCBA9876543210, 0x0F0F0F0FF0F0F0F0];
+    let original_slice = BitmapSlice::new(&original, 0..192);
+
+    for (range, n) in [(0..192, 0), (0..192, 64), (3..190, 1), (3..190, 70), (7..135, 127), (7..135, 128), (7..135, 500)] {
+        let size = range.len();
+
+        let mut shifted = original;
+        BitmapSliceMut::new(&mut shifted, range.clone()).shift_left(n);
+        let shifted_slice = BitmapSlice::new(&shifted, 0..192);
+        for bit_index in 0..192 {
+            let expected = if !range.contains(&bit_index) {
+                original_slice.get_bit(bit_index)
+
+            } else {
+                let logical_index = bit_index - range.start;
+                (logical_index >= n) && original_slice.get_bit(bit_index - n)
+            };
+
+            assert_eq!(shifted_slice.get_bit(bit_index), expected);
+        }
+
+        let mut shifted = original;
+        BitmapSliceMut::new(&mut shifted, range.clone()).shift_right(n);
+        let shifted_slice = BitmapSlice::new(&shifted, 0..192);
+        for bit_index in 0..192 {
+            let expected = if !range.contains(&bit_index) {
+                original_slice.get_bit(bit_index)
+
+            } else {
+                let logical_index = bit_index - range.start;
+                ((logical_index + n) < size) && original_slice.get_bit(bit_index + n)
+            };
+
+            assert_eq!(shifted_slice.get_bit(bit_index), expected);
+        }
+    }
+}
+
+fn check_rotate<B: BitStore>(original: &[B], range: Range<usize>, n: usize) {
+    let original_slice = BitmapSlice::new(original, 0..(original.len() * B::BIT_COUNT));
+    let size = range.len();
+
+    for rotate_left in [true, false] {
+        let mut rotated = original.to_vec();
+        let mut rotated_slice = BitmapSliceMut::new(&mut rotated, range.clone());
+        if rotate_left {
+            rotated_slice.rotate_left(n);
+
+        } else {
+            rotated_slice.rotate_right(n);
+        }
+
+        let rotated_slice = BitmapSlice::new(&rotated, 0..(original.len() * B::BIT_COUNT));
+        for bit_index in 0..rotated_slice.size() {
+            let expected = if !range.contains(&bit_index) {
+                original_slice.get_bit(bit_index)
+
+            } else {
+                let logical_index = bit_index - range.start;
+                let source_index = if rotate_left {
+                    (logical_index + size - (n % size)) % size
+
+                } else {
+                    (logical_index + n) % size
+                };
+
+                original_slice.get_bit(range.start + source_index)
+            };
+
+            assert_eq!(rotated_slice.get_bit(bit_index), expected);
+        }
+    }
+}
+
+#[test]
+fn test_rotate() {
+    let bytes = [0b1011_0010u8, 0b0111_1000, 0b0000_0001, 0b1100_1010, 0b0001_0011];
+    let words = [0x0123456789ABCDEFu64, 0xFEDCBA9876543210];
+    let bools: Vec<bool> = (0..19).map(|bit_index| (bit_index % 3) == 0).collect();
+
+    for n in [0, 1, 3, 8, 13, 33, 34, 35, 70] {
+        check_rotate(&bytes, 0..40, n);
+        check_rotate(&bytes, 3..37, n);
+        check_rotate(&bytes, 5..6, n);
+        check_rotate(&words, 7..128, n);
+        check_rotate(&words, 60..70, n);
+        check_rotate(&bools, 2..17, n);
+    }
+
+    let mut buffer = bytes;
+    BitmapSliceMut::new(&mut buffer, 3..37).rotate_left(34);
+    BitmapSliceMut::new(&mut buffer, 3..37).rotate_right(68);
+    BitmapSliceMut::new(&mut buffer, 3..3).rotate_right(5);
+    assert_eq!(buffer, bytes);
+}
+
+#[test]
+fn test_aligned_interior() {
+    let buffer = [0x11111111u32, 0x22222222, 0x33333333, 0x44444444];
+
+    let slice = BitmapSlice::new(&buffer, 5..105);
+    assert_eq!(slice.aligned_interior(), Some((&buffer[1..3], 27, 9)));
+
+    let slice = BitmapSlice::new(&buffer, 32..128);
+    assert_eq!(slice.aligned_interior(), Some((&buffer[1..4], 0, 0)));
+
+    let slice = BitmapSlice::new(&buffer, 5..40);
+    assert_eq!(slice.aligned_interior(), None);
+}
+
+#[test]
+fn test_chained_bitmap() {
+    let first = [0u8, 0];
+    let second = [0b0010_0000u8, 0b0000_0001];
+    let chain = ChainedBitmap::new(vec![BitmapSlice::new(&first, 3..16), BitmapSlice::new(&second, 4..16)]);
+
+    assert_eq!(chain.size(), 25);
+    assert_eq!(chain.find_first_set(), Some(14));
+    assert_eq!(chain.find_next_set_from(15), Some(17));
+    assert_eq!(chain.find_next_set_in_range(15..17), None);
+    assert_eq!(chain.find_next_clear_from(14), Some(15));
+    assert!(chain.get_bit(14));
+    assert!(!chain.get_bit(12));
+    assert_eq!(chain.count_ones(), 2);
+    assert_eq!(chain.partition_indices().0, vec![14, 17]);
+}
+
+#[test]
+fn test_range_iter_size_hint() {
+    let buffer = [0b01010101u8, 0b11111111];
+    let slice = BitmapSlice::new(&buffer, 0..16);
+
+    let mut range_iter = slice.range_iter();
+    assert_eq!(range_iter.size_hint(), (0, Some(8)));
+    assert_eq!(range_iter.next(), Some((0, 1)));
+    assert_eq!(range_iter.size_hint(), (0, Some(8)));
+    assert_eq!(range_iter.next_back(), Some((8, 8)));
+    assert_eq!(range_iter.size_hint(), (0, Some(4)));
+
+    let ranges: Vec<(usize, usize)> = slice.range_iter().collect();
+    assert!(ranges.len() <= slice.range_iter().size_hint().1.unwrap());
+}
+
+#[test]
+fn test_chunks() {
+    let mut buffer = [0b10110111u8, 0b11111111, 0b00010001];
+    let slice = BitmapSlice::new(&buffer, 3..24);
+
+    let chunks: Vec<BitmapSlice<'_, u8>> = slice.chunks(8).collect();
+    assert_eq!(chunks.iter().map(|chunk| chunk.size()).collect::<Vec<_>>(), vec![8, 8, 5]);
+    for (k, chunk) in chunks.iter().enumerate() {
+        for bit_index in 0..chunk.size() {
+            assert_eq!(chunk.get_bit(bit_index), slice.get_bit(k * 8 + bit_index));
+        }
+    }
+
+    let mut slice = BitmapSliceMut::new(&mut buffer, 3..24);
+    for mut chunk in slice.chunks_mut(5) {
+        chunk.clear_bit(0);
+    }
+
+    assert_eq!(slice.partition_indices().0, vec![1, 2, 4, 6, 7, 8, 9, 11, 12, 13, 17]);
+}
+
+#[test]
+fn test_windows() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001];
+    let slice = BitmapSlice::new(&buffer, 3..24);
+
+    let windows = slice.windows(8);
+    assert_eq!(windows.len(), slice.size() - 8 + 1);
+
+    for (start, window) in windows.enumerate() {
+        assert_eq!(window.size(), 8);
+        assert_eq!(window, slice.subslice(start..(start + 8)));
+    }
+
+    assert_eq!(slice.windows(21).count(), 1);
+    assert_eq!(slice.windows(22).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_windows_zero_size() {
+    let buffer = [0u8; 2];
+    BitmapSlice::new(&buffer, 0..16).windows(0);
+}
+
+#[test]
+fn test_keep_range() {
+    let mut buffer = [u8::MAX; 4];
+    BitmapSliceMut::new(&mut buffer, 3..29).keep_range(6..10);
+    assert_eq!(buffer, [0b0000_0111, 0b0001_1110, 0x00, 0b1110_0000]);
+}
+
+#[test]
+fn test_has_isolated_set_bits() {
+    let paired = [0b1100_0110u8, 0b0000_0011, 0b1110_0000];
+    let isolated = [0b1100_0110u8, 0b0001_0011, 0b1110_0000];
+
+    for (start, end) in [(0, 24), (1, 23), (7, 17)] {
+        let paired_slice = BitmapSlice::new(&paired, start..end);
+        let isolated_slice = BitmapSlice::new(&isolated, start..end);
+
+        assert!(!paired_slice.has_isolated_set_bits());
+        assert!(isolated_slice.has_isolated_set_bits());
+        assert_eq!(paired_slice.has_isolated_set_bits(), paired_slice.range_iter().any(|(_, count)| count == 1));
+    }
+
+    assert!(BitmapSlice::new(&paired, 2..10).has_isolated_set_bits());
+    assert!(BitmapSlice::new(&paired, 0..2).has_isolated_set_bits());
+    assert!(!BitmapSlice::new(&paired, 0..1).has_isolated_set_bits());
+
+    let booleans = [false, true, true, false, true];
+    assert!(!BitmapSlice::new(&booleans, 0..4).has_isolated_set_bits());
+    assert!(BitmapSlice::new(&booleans, 0..5).has_isolated_set_bits());
+}
+
+#[test]
+fn test_bit_store_counts() {
+    fn check<B: BitStore>() {
+        assert_eq!(B::ZERO.count_ones(), 0);
+        assert_eq!(B::ZERO.leading_zeros(), B::BIT_COUNT);
+        assert_eq!(B::ZERO.trailing_zeros(), B::BIT_COUNT);
+        assert_eq!(B::MAX.count_ones(), B::BIT_COUNT);
+        assert_eq!(B::MAX.leading_zeros(), 0);
+
+        for bit_index in 0..B::BIT_COUNT {
+            let mask = B::create_bit_mask(bit_index);
+            assert_eq!(mask.count_ones(), 1);
+            assert_eq!(mask.leading_zeros(), B::BIT_COUNT - 1 - bit_index);
+            assert_eq!(mask.trailing_zeros(), bit_index);
+
+            let range_mask = B::create_range_mask(0, bit_index + 1);
+            assert_eq!(range_mask.count_ones(), bit_index + 1);
+            assert_eq!(range_mask.leading_zeros(), B::BIT_COUNT - 1 - bit_index);
+        }
+    }
+
+    check::<bool>();
+    check::<u8>();
+    check::<u16>();
+    check::<u32>();
+    check::<u64>();
+    check::<u128>();
+    check::<usize>();
+    check::<crate::store::ByteArray<3>>();
+
+    for bit_index in 0..16 {
+        let mask = Msb0::create_bit_mask::<u16>(bit_index);
+        assert_eq!(mask, 0x8000 >> bit_index);
+        assert_eq!(Msb0::leading_zeros(mask), 15 - bit_index);
+        assert_eq!(Msb0::trailing_zeros(mask), bit_index);
+        assert_eq!(Msb0::create_range_mask::<u16>(0, bit_index + 1), (0xFFFF_0000u32 >> (bit_index + 1)) as u16);
+    }
+}
+
+#[test]
+fn test_for_each_range() {
+    let buffer = [0b10110111u8, 0b11111111, 0b00010001, 0b10000001];
+    let booleans = [true, true, false, true, false, false, true];
+
+    for (start, end) in [(0, 32), (3, 29), (7, 17), (8, 16), (12, 12)] {
+        let slice = BitmapSlice::new(&buffer, start..end);
+
+        let mut set_ranges = Vec::new();
+        slice.for_each_set_range(|range_start, range_count| set_ranges.push((range_start, range_count)));
+        assert_eq!(set_ranges, slice.range_iter().collect::<Vec<_>>());
+
+        let mut clear_ranges = Vec::new();
+        slice.for_each_clear_range(|range_start, range_count| clear_ranges.push((range_start, range_count)));
+        assert_eq!(clear_ranges, slice.clear_range_iter().collect::<Vec<_>>());
+    }
+
+    let slice = BitmapSlice::new(&booleans, 1..7);
+    let mut set_ranges = Vec::new();
+    slice.for_each_set_range(|range_start, range_count| set_ranges.push((range_start, range_count)));
+    assert_eq!(set_ranges, [(0, 1), (2, 1), (5, 1)]);
+}
+
+#[test]
+fn test_dyn_traits() {
+    let mut buffer = [0b10110111u8, 0b00000001];
+    let mut slice = BitmapSliceMut::new(&mut buffer, 2..14);
+
+    let opts: &dyn BitmapOpts = &slice;
+    assert_eq!(opts.count_ones(), 5);
+    assert_eq!(opts.find_first_clear(), Some(1));
+
+    let opts_mut: &mut dyn BitmapOptsMut = &mut slice;
+    opts_mut.set_bit(1);
+    opts_mut.clear_bit(0);
+    assert_eq!(opts_mut.count_ones(), 5);
+    assert_eq!(buffer, [0b10111011u8, 0b00000001]);
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut buffer = [0b10101010u8, 0b00001111];
+    let mut slice = BitmapSliceMut::new(&mut buffer, 3..13);
+    assert_eq!(slice.iter_mut().len(), 10);
+
+    for (bit_index, mut bit) in slice.iter_mut().enumerate() {
+        assert_eq!(bit.get(), (0b00001111_10101010u16 >> (bit_index + 3)) & 1 != 0);
+        bit.set(bit_index % 3 == 0);
+        assert_eq!(bit.get(), bit_index % 3 == 0);
+    }
+
+    assert_eq!(slice.iter().collect::<Vec<_>>(), [0, 3, 6, 9]);
+    assert_eq!(buffer, [0b01001010, 0b00010010]);
+
+    let mut booleans = [false; 4];
+    let mut slice = BitmapSliceMut::new(&mut booleans, 0..4);
+    for mut bit in slice.iter_mut().skip(1).step_by(2) {
+        bit.set(true);
+    }
+
+    assert_eq!(booleans, [false, true, false, true]);
+}
+
+#[test]
+fn test_split_first_last() {
+    let buffer = [0b10000001u8, 0b01000000];
+    let slice = BitmapSlice::new(&buffer, 7..15);
+
+    let (first_bit, remaining) = slice.split_first().unwrap();
+    assert!(first_bit);
+    assert_eq!(remaining.size(), 7);
+    assert_eq!(remaining.first_bit_offset, 0);
+    assert_eq!(remaining.iter().collect::<Vec<_>>(), [6]);
+
+    let (last_bit, remaining) = remaining.split_last().unwrap();
+    assert!(last_bit);
+    assert_eq!(remaining.size(), 6);
+    assert_eq!(remaining.count_ones(), 0);
+
+    let mut bits = Vec::new();
+    let mut remaining = slice;
+    while let Some((bit, rest)) = remaining.split_first() {
+        bits.push(bit);
+        remaining = rest;
+    }
+
+    assert_eq!(bits, [true, false, false, false, false, false, false, true]);
+    assert!(remaining.split_first().is_none());
+    assert!(remaining.split_last().is_none());
+}
+
+#[test]
+fn test_raw_parts() {
+    let mut buffer = [0u16, 0b1000_0000_0000_0001, 0];
+    let (buffer_address, first_bit_offset, bit_count) = {
+        let slice = BitmapSlice::new(&buffer, 20..37);
+        slice.as_raw_parts()
+    };
+
+    assert_eq!(buffer_address.as_ptr() as *const u16, &buffer[1] as *const u16);
+    assert_eq!((first_bit_offset, bit_count), (4, 17));
+
+    let slice = unsafe { BitmapSlice::<u16>::from_raw_parts(buffer_address, first_bit_offset, bit_count) };
+    assert_eq!(slice.iter().collect::<Vec<_>>(), [11]);
+
+    let buffer_address = core::ptr::NonNull::new(buffer.as_mut_ptr()).unwrap();
+    let mut slice = unsafe { BitmapSliceMut::<u16>::from_raw_parts(buffer_address, 15, 2) };
+    slice.set_bit_range(0..2);
+    assert_eq!(buffer, [0b1000_0000_0000_0000, 0b1000_0000_0000_0001, 0]);
+}
+
+#[test]
+fn test_as_word_slice() {
+    let mut buffer = [0x1234u16, 0x5678, 0x9ABC, 0xDEF0];
+    let slice = BitmapSlice::new(&buffer, 20..36);
+    assert_eq!(unsafe { slice.as_word_slice() }, [0x5678, 0x9ABC]);
+
+    let slice = BitmapSlice::new(&buffer, 16..32);
+    assert_eq!(unsafe { slice.as_word_slice() }, [0x5678]);
+
+    let slice = BitmapSlice::new(&buffer, 16..16);
+    assert!(unsafe { slice.as_word_slice() }.is_empty());
+
+    let mut slice = BitmapSliceMut::new(&mut buffer, 4..20);
+    let words = unsafe { slice.as_word_slice_mut() };
+    assert_eq!(words.len(), 2);
+    words[1] = 0;
+    assert_eq!(buffer, [0x1234, 0, 0x9ABC, 0xDEF0]);
+}
+
+#[test]
+fn test_try_subslice() {
+    let mut buffer = [0b10100101u8, 0b00001111];
+    let slice = BitmapSlice::new(&buffer, 2..14);
+
+    assert_eq!(slice.try_subslice(1..6).unwrap().iter().collect::<Vec<_>>(), [2, 4]);
+    assert_eq!(slice.try_subslice(12..12).map(|subslice| subslice.size()), Some(0));
+    assert_eq!(slice.try_subslice(0..12).map(|subslice| subslice.size()), Some(12));
+    assert!(slice.try_subslice(3..13).is_none());
+    assert!(slice.try_subslice(10..14).is_none());
+    assert!(slice.try_subslice(Range { start: 5, end: 4 }).is_none());
+
+    let mut slice = BitmapSliceMut::new(&mut buffer, 2..14);
+    assert!(slice.try_subslice_mut(11..13).is_none());
+    slice.try_subslice_mut(4..8).unwrap().set_bit_range(0..4);
+    assert_eq!(buffer, [0b11100101, 0b00001111]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid bit range [4:10] for bit map slice of size 8")]
+fn test_subslice_past_end() {
+    let buffer = [0u8, 0];
+    BitmapSlice::new(&buffer, 4..12).subslice(4..10);
+}
+
+#[test]
+fn test_leading_trailing_zeros() {
+    fn check<B: BitStore>(buffer: &[B]) {
+        let total_bits = buffer.len() * B::BIT_COUNT;
+        for start in [0, 1, 5, B::BIT_COUNT - 1, B::BIT_COUNT + 3] {
+            for end in [start, start + 1, total_bits / 2, total_bits - 3, total_bits] {
+                if end < start {
+                    continue;
+                }
+
+                let slice = BitmapSlice::new(buffer, start..end);
+                let set_bits = slice.iter().collect::<Vec<_>>();
+                assert_eq!(slice.trailing_zeros(), set_bits.first().copied().unwrap_or(slice.size()));
+                assert_eq!(slice.leading_zeros(), set_bits.last().map_or(slice.size(), |last| slice.size() - last - 1));
+            }
+        }
+    }
+
+    check(&[0u8, 0b00100000, 0, 0b00000100, 0, 0]);
+    check(&[0u16, 0b0000000000001000, 0, 0]);
+    check(&[0u32, 0, 1 << 17, 0]);
+    check(&[0u64, 1 << 40, 1, 0]);
+    check(&[0u128, 1 << 100, 0]);
+    check(&[0u8; 4]);
+
+    let buffer = [0u64; 3];
+    assert_eq!(BitmapSlice::new(&buffer, 7..150).trailing_zeros(), 143);
+    assert_eq!(BitmapSlice::new(&buffer, 7..150).leading_zeros(), 143);
+}
+
+#[test]
+fn test_reverse() {
+    use crate::store::ByteArray;
+
+    fn check<B: BitStore, O: BitOrder>(buffer: &mut [B], order: O) {
+        let total_bits = buffer.len() * B::BIT_COUNT;
+        for start in [0, 1, 3, B::BIT_COUNT - 1, B::BIT_COUNT + 2] {
+            for end in [start, start + 1, start + 2, start + 7, total_bits - 5, total_bits] {
+                if (end < start) || (end > total_bits) {
+                    continue;
+                }
+
+                let original = buffer.to_vec();
+                let before = BitmapSlice::with_order(&original, 0..total_bits, order).iter().collect::<Vec<_>>();
+                BitmapSliceMut::with_order(buffer, start..end, order).reverse();
+
+                let after = BitmapSlice::with_order(buffer, 0..total_bits, order).iter().collect::<Vec<_>>();
+                let mut expected = before.iter()
+                    .map(|bit_index| if (start..end).contains(bit_index) { start + end - 1 - bit_index } else { *bit_index })
+                    .collect::<Vec<_>>();
+                expected.sort_unstable();
+                assert_eq!(after, expected, "reverse of [{}:{}]", start, end);
+
+                buffer.copy_from_slice(&original);
+            }
+        }
+    }
+
+    check(&mut [0b10110010u8, 0b01000111, 0b11100001, 0b00010100], Lsb0);
+    check(&mut [0b1011001001000111u16, 0b1110000100010100, 0b0000000011111111], Lsb0);
+    check(&mut [0x0123_4567_89AB_CDEFu64, 0xF0F0_0000_FFFF_1248], Lsb0);
+    check(&mut [0b10110010u8, 0b01000111, 0b11100001], Msb0);
+    check(&mut [0b1011001001000111u16, 0b1110000100010100, 0b0000000011111111], Msb0);
+    check(&mut [ByteArray([0b10110010u8, 0b01000111, 0b11100001]), ByteArray([0b00010100, 0, 0b11111111])], Lsb0);
+    check(&mut [true, false, false, true, true, false, true, false], Lsb0);
+
+    let mut buffer = [0b10110000u8, 0b00000001];
+    BitmapSliceMut::with_order(&mut buffer, 0..8, Msb0).reverse();
+    assert_eq!(buffer, [0b00001101, 0b00000001]);
+    assert_eq!(ByteArray([0b00000001u8, 0b00000110, 0]).reverse_bits(), ByteArray([0, 0b01100000, 0b10000000]));
+
+    let mut buffer = [0b00000001u8, 0b00000000];
+    BitmapSliceMut::new(&mut buffer, 0..10).reverse();
+    assert_eq!(buffer, [0b00000000, 0b00000010]);
+}
+
+#[test]
+fn test_swap_bits() {
+    let mut buffer = [0b10110010u8, 0b01000111, 0b11100001];
+    let mut model = BitmapSlice::new(&buffer, 3..22).into_iter().fold(vec![false; 19], |mut model, bit_index| {
+        model[bit_index] = true;
+        model
+    });
+
+    let mut slice = BitmapSliceMut::new(&mut buffer, 3..22);
+    for (i, j) in [(0, 1), (2, 4), (0, 18), (5, 12), (7, 7), (18, 3), (9, 10), (4, 2)] {
+        slice.swap_bits(i, j);
+        model.swap(i, j);
+        assert_eq!((0..19).map(|bit_index| slice.get_bit(bit_index)).collect::<Vec<_>>(), model);
+    }
+
+    let mut booleans = [true, false, false];
+    let mut slice = BitmapSliceMut::new(&mut booleans, 0..3);
+    slice.swap_bits(0, 2);
+    slice.swap_bits(1, 1);
+    assert_eq!(booleans, [false, false, true]);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_bits_out_of_bounds() {
+    let mut buffer = [0u8; 2];
+    BitmapSliceMut::new(&mut buffer, 3..12).swap_bits(2, 9);
+}
@@ -1,6 +1,9 @@
 
-use std::cmp::PartialEq;
-use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+use core::cmp::PartialEq;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+use core::sync::atomic::{AtomicU8, AtomicU16, AtomicU32, AtomicUsize, Ordering};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
 
 ///
 /// Returns the minimum required size of an array of type B to contain enough
@@ -10,6 +13,14 @@ pub const fn array_size_for_bit_count<B: BitStore>(bit_count: usize) -> usize {
     crate::polyfill::div_ceil(bit_count, B::BIT_COUNT)
 }
 
+///
+/// Returns the number of slots of type B needed to hold `bits` bits. This is usable in
+/// array length position, such as `[u64; bits_to_slots::<u64>(100)]`.
+/// 
+pub const fn bits_to_slots<B: BitStore>(bits: usize) -> usize {
+    array_size_for_bit_count::<B>(bits)
+}
+
 ///
 /// This trait represents an abstraction over storage that contains indexable
 /// bits.
@@ -32,6 +43,12 @@ pub trait BitStore:
     /// 
     const BIT_COUNT: usize;
     
+    ///
+    /// A const containing the number of bytes in the little endian representation
+    /// of this type.
+    /// 
+    const BYTE_COUNT: usize;
+
     ///
     /// A const containing the 0 (no bits set) value for this type.
     /// 
@@ -55,16 +72,57 @@ pub trait BitStore:
     /// 
     fn create_range_mask(start_bit: usize, bit_count: usize) -> Self;
     
+    ///
+    /// Counts the number of set bits in a value of this type.
+    /// 
+    fn count_ones(self) -> usize;
+
+    ///
+    /// Counts the number of leading zeros in a value of this type.
+    /// 
+    fn leading_zeros(self) -> usize;
+
     ///
     /// Counts the number of trailing zeros in a value of this type.
     /// 
     fn trailing_zeros(self) -> usize;
 
+    ///
+    /// Shifts the bits in a value of this type towards the most significant bit.
+    /// Implementations can assume that shift < Self::BIT_COUNT.
+    /// 
+    fn shift_left(self, shift: usize) -> Self;
+
+    ///
+    /// Shifts the bits in a value of this type towards the least significant bit.
+    /// Implementations can assume that shift < Self::BIT_COUNT.
+    /// 
+    fn shift_right(self, shift: usize) -> Self;
+
+    ///
+    /// Reverses the order of the bits in a value of this type, so that bit `0` becomes bit
+    /// `Self::BIT_COUNT - 1`.
+    /// 
+    fn reverse_bits(self) -> Self;
+
+    ///
+    /// Writes the little endian representation of this value into `bytes`.
+    /// Implementations can assume that bytes.len() == Self::BYTE_COUNT.
+    /// 
+    fn write_le_bytes(self, bytes: &mut [u8]);
+
+    ///
+    /// Reads a value of this type from its little endian representation in `bytes`.
+    /// Implementations can assume that bytes.len() == Self::BYTE_COUNT.
+    /// 
+    fn read_le_bytes(bytes: &[u8]) -> Self;
+
 }
 
 impl BitStore for bool {
 
     const BIT_COUNT: usize = 1;
+    const BYTE_COUNT: usize = 1;
     const ZERO: Self = false;
     const MAX: Self = true;
 
@@ -76,6 +134,19 @@ impl BitStore for bool {
         bit_count != 0
     }
 
+    fn count_ones(self) -> usize {
+        self as usize
+    }
+
+    fn leading_zeros(self) -> usize {
+        if self {
+            0
+
+        } else {
+            1
+        }
+    }
+
     fn trailing_zeros(self) -> usize {
         if self {
             0
@@ -84,12 +155,33 @@ impl BitStore for bool {
             1
         }
     }
+
+    fn shift_left(self, _shift: usize) -> Self {
+        self
+    }
+
+    fn shift_right(self, _shift: usize) -> Self {
+        self
+    }
+
+    fn reverse_bits(self) -> Self {
+        self
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes[0] = self as u8;
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        bytes[0] != 0
+    }
     
 }
 
 impl BitStore for u8 {
 
     const BIT_COUNT: usize = Self::BITS as usize;
+    const BYTE_COUNT: usize = core::mem::size_of::<Self>();
     const ZERO: Self = 0;
     const MAX: Self = Self::MAX;
 
@@ -106,15 +198,46 @@ impl BitStore for u8 {
         }
     }
 
+    fn count_ones(self) -> usize {
+        Self::count_ones(self) as usize
+    }
+
+    fn leading_zeros(self) -> usize {
+        Self::leading_zeros(self) as usize
+    }
+
     fn trailing_zeros(self) -> usize {
         Self::trailing_zeros(self) as usize
     }
 
+    fn shift_left(self, shift: usize) -> Self {
+        self << shift
+    }
+
+    fn shift_right(self, shift: usize) -> Self {
+        self >> shift
+    }
+
+    fn reverse_bits(self) -> Self {
+        Self::reverse_bits(self)
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&Self::to_le_bytes(self));
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        let mut le_bytes = [0; core::mem::size_of::<Self>()];
+        le_bytes.copy_from_slice(bytes);
+        Self::from_le_bytes(le_bytes)
+    }
+
 }
 
 impl BitStore for u16 {
 
     const BIT_COUNT: usize = Self::BITS as usize;
+    const BYTE_COUNT: usize = core::mem::size_of::<Self>();
     const ZERO: Self = 0;
     const MAX: Self = Self::MAX;
 
@@ -131,15 +254,46 @@ impl BitStore for u16 {
         }
     }
 
+    fn count_ones(self) -> usize {
+        Self::count_ones(self) as usize
+    }
+
+    fn leading_zeros(self) -> usize {
+        Self::leading_zeros(self) as usize
+    }
+
     fn trailing_zeros(self) -> usize {
         Self::trailing_zeros(self) as usize
     }
 
+    fn shift_left(self, shift: usize) -> Self {
+        self << shift
+    }
+
+    fn shift_right(self, shift: usize) -> Self {
+        self >> shift
+    }
+
+    fn reverse_bits(self) -> Self {
+        Self::reverse_bits(self)
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&Self::to_le_bytes(self));
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        let mut le_bytes = [0; core::mem::size_of::<Self>()];
+        le_bytes.copy_from_slice(bytes);
+        Self::from_le_bytes(le_bytes)
+    }
+
 }
 
 impl BitStore for u32 {
 
     const BIT_COUNT: usize = u32::BITS as usize;
+    const BYTE_COUNT: usize = core::mem::size_of::<Self>();
     const ZERO: Self = 0;
     const MAX: Self = u32::MAX;
 
@@ -156,15 +310,46 @@ impl BitStore for u32 {
         }
     }
 
+    fn count_ones(self) -> usize {
+        Self::count_ones(self) as usize
+    }
+
+    fn leading_zeros(self) -> usize {
+        Self::leading_zeros(self) as usize
+    }
+
     fn trailing_zeros(self) -> usize {
         Self::trailing_zeros(self) as usize
     }
 
+    fn shift_left(self, shift: usize) -> Self {
+        self << shift
+    }
+
+    fn shift_right(self, shift: usize) -> Self {
+        self >> shift
+    }
+
+    fn reverse_bits(self) -> Self {
+        Self::reverse_bits(self)
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&Self::to_le_bytes(self));
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        let mut le_bytes = [0; core::mem::size_of::<Self>()];
+        le_bytes.copy_from_slice(bytes);
+        Self::from_le_bytes(le_bytes)
+    }
+
 }
 
 impl BitStore for u64 {
 
     const BIT_COUNT: usize = Self::BITS as usize;
+    const BYTE_COUNT: usize = core::mem::size_of::<Self>();
     const ZERO: Self = 0;
     const MAX: Self = Self::MAX;
 
@@ -181,15 +366,46 @@ impl BitStore for u64 {
         }
     }
 
+    fn count_ones(self) -> usize {
+        Self::count_ones(self) as usize
+    }
+
+    fn leading_zeros(self) -> usize {
+        Self::leading_zeros(self) as usize
+    }
+
     fn trailing_zeros(self) -> usize {
         Self::trailing_zeros(self) as usize
     }
 
+    fn shift_left(self, shift: usize) -> Self {
+        self << shift
+    }
+
+    fn shift_right(self, shift: usize) -> Self {
+        self >> shift
+    }
+
+    fn reverse_bits(self) -> Self {
+        Self::reverse_bits(self)
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&Self::to_le_bytes(self));
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        let mut le_bytes = [0; core::mem::size_of::<Self>()];
+        le_bytes.copy_from_slice(bytes);
+        Self::from_le_bytes(le_bytes)
+    }
+
 }
 
 impl BitStore for u128 {
 
     const BIT_COUNT: usize = Self::BITS as usize;
+    const BYTE_COUNT: usize = core::mem::size_of::<Self>();
     const ZERO: Self = 0;
     const MAX: Self = Self::MAX;
 
@@ -206,15 +422,46 @@ impl BitStore for u128 {
         }
     }
 
+    fn count_ones(self) -> usize {
+        Self::count_ones(self) as usize
+    }
+
+    fn leading_zeros(self) -> usize {
+        Self::leading_zeros(self) as usize
+    }
+
     fn trailing_zeros(self) -> usize {
         Self::trailing_zeros(self) as usize
     }
+
+    fn shift_left(self, shift: usize) -> Self {
+        self << shift
+    }
+
+    fn shift_right(self, shift: usize) -> Self {
+        self >> shift
+    }
+
+    fn reverse_bits(self) -> Self {
+        Self::reverse_bits(self)
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&Self::to_le_bytes(self));
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        let mut le_bytes = [0; core::mem::size_of::<Self>()];
+        le_bytes.copy_from_slice(bytes);
+        Self::from_le_bytes(le_bytes)
+    }
     
 }
 
 impl BitStore for usize {
 
     const BIT_COUNT: usize = usize::BITS as usize;
+    const BYTE_COUNT: usize = core::mem::size_of::<Self>();
     const ZERO: Self = 0;
     const MAX: Self = usize::MAX;
 
@@ -231,8 +478,559 @@ impl BitStore for usize {
         }
     }
 
+    fn count_ones(self) -> usize {
+        Self::count_ones(self) as usize
+    }
+
+    fn leading_zeros(self) -> usize {
+        Self::leading_zeros(self) as usize
+    }
+
     fn trailing_zeros(self) -> usize {
         Self::trailing_zeros(self) as usize
     }
 
+    fn shift_left(self, shift: usize) -> Self {
+        self << shift
+    }
+
+    fn shift_right(self, shift: usize) -> Self {
+        self >> shift
+    }
+
+    fn reverse_bits(self) -> Self {
+        Self::reverse_bits(self)
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&Self::to_le_bytes(self));
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        let mut le_bytes = [0; core::mem::size_of::<Self>()];
+        le_bytes.copy_from_slice(bytes);
+        Self::from_le_bytes(le_bytes)
+    }
+
+}
+
+mod seal {
+
+    pub trait Sealed {}
+
+}
+
+///
+/// This trait represents the order in which the bits of a [BitStore] slot are numbered and
+/// allows bitmaps to be generic over that order. Every routine that maps a bit index within
+/// a slot to a physical bit of the slot goes through this trait.
+/// 
+pub trait BitOrder: self::seal::Sealed + Copy + Default {
+
+    ///
+    /// Creates a mask used to index the bit at `bit_index` of a slot.
+    /// Implementations can assume that bit_index < B::BIT_COUNT.
+    /// 
+    fn create_bit_mask<B: BitStore>(bit_index: usize) -> B;
+
+    ///
+    /// Creates a mask used to index `bit_count` bits of a slot starting at `start_bit`.
+    /// Implementations can assume that start_bit < B::BIT_COUNT and
+    /// (start_bit + bit_count) <= B::BIT_COUNT.
+    /// 
+    fn create_range_mask<B: BitStore>(start_bit: usize, bit_count: usize) -> B;
+
+    ///
+    /// Counts the number of clear bits of `value` after its highest set bit index.
+    /// 
+    fn leading_zeros<B: BitStore>(value: B) -> usize;
+
+    ///
+    /// Counts the number of clear bits of `value` before its lowest set bit index.
+    /// 
+    fn trailing_zeros<B: BitStore>(value: B) -> usize;
+
+    ///
+    /// Moves every bit of `value` `shift` indices up, filling the lowest indices with clear
+    /// bits. Implementations can assume that shift < B::BIT_COUNT.
+    /// 
+    fn shift_left<B: BitStore>(value: B, shift: usize) -> B;
+
+    ///
+    /// Moves every bit of `value` `shift` indices down, filling the highest indices with
+    /// clear bits. Implementations can assume that shift < B::BIT_COUNT.
+    /// 
+    fn shift_right<B: BitStore>(value: B, shift: usize) -> B;
+}
+
+///
+/// This type numbers the bits of a slot least significant bit first, so bit 0 is the least
+/// significant bit of the slot. This is the default order for bitmaps and bitmap slices.
+/// 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Lsb0;
+
+impl self::seal::Sealed for Lsb0 { }
+impl BitOrder for Lsb0 {
+
+    fn create_bit_mask<B: BitStore>(bit_index: usize) -> B {
+        B::create_bit_mask(bit_index)
+    }
+
+    fn create_range_mask<B: BitStore>(start_bit: usize, bit_count: usize) -> B {
+        B::create_range_mask(start_bit, bit_count)
+    }
+
+    fn leading_zeros<B: BitStore>(value: B) -> usize {
+        value.leading_zeros()
+    }
+
+    fn trailing_zeros<B: BitStore>(value: B) -> usize {
+        value.trailing_zeros()
+    }
+
+    fn shift_left<B: BitStore>(value: B, shift: usize) -> B {
+        value.shift_left(shift)
+    }
+
+    fn shift_right<B: BitStore>(value: B, shift: usize) -> B {
+        value.shift_right(shift)
+    }
+
+}
+
+///
+/// This type numbers the bits of a slot most significant bit first, so bit 0 is the most
+/// significant bit of the slot. This is useful for interoperating with formats that number
+/// bits MSB-first, such as many wire protocols.
+/// 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Msb0;
+
+impl self::seal::Sealed for Msb0 { }
+impl BitOrder for Msb0 {
+
+    fn create_bit_mask<B: BitStore>(bit_index: usize) -> B {
+        B::create_bit_mask(B::BIT_COUNT - 1 - bit_index)
+    }
+
+    fn create_range_mask<B: BitStore>(start_bit: usize, bit_count: usize) -> B {
+        if bit_count == 0 {
+            B::ZERO
+
+        } else {
+            B::create_range_mask(B::BIT_COUNT - start_bit - bit_count, bit_count)
+        }
+    }
+
+    fn leading_zeros<B: BitStore>(value: B) -> usize {
+        value.trailing_zeros()
+    }
+
+    fn trailing_zeros<B: BitStore>(value: B) -> usize {
+        value.leading_zeros()
+    }
+
+    fn shift_left<B: BitStore>(value: B, shift: usize) -> B {
+        value.shift_right(shift)
+    }
+
+    fn shift_right<B: BitStore>(value: B, shift: usize) -> B {
+        value.shift_left(shift)
+    }
+
+}
+
+///
+/// A [BitStore] adapter that treats an array of `N` bytes as a single slot of `N * 8` bits.
+/// This allows bit widths that are not a power of two, such as 24 bit slots with
+/// `ByteArray<3>`. The bytes are little endian, so bit 0 is the least significant bit of
+/// the first byte.
+/// 
+/// Slices track the offset of their first bit within a slot in a `u8`, so `N` may be at most
+/// 32. Using a larger array as a [BitStore] fails to compile.
+/// 
+/// ```compile_fail
+/// # use bitmap::prelude::*;
+/// # use bitmap::store::ByteArray;
+/// let buffer = [ByteArray([0u8; 40]); 2];
+/// let slice = BitmapSlice::new(&buffer, 0..640);
+/// ```
+/// 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(transparent)]
+pub struct ByteArray<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> BitAnd<ByteArray<N>> for ByteArray<N> {
+
+    type Output = ByteArray<N>;
+
+    fn bitand(mut self, rhs: ByteArray<N>) -> Self::Output {
+        self &= rhs;
+        self
+    }
+
+}
+
+impl<const N: usize> BitAndAssign<ByteArray<N>> for ByteArray<N> {
+
+    fn bitand_assign(&mut self, rhs: ByteArray<N>) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(byte, rhs)| *byte &= rhs);
+    }
+
+}
+
+impl<const N: usize> BitOr<ByteArray<N>> for ByteArray<N> {
+
+    type Output = ByteArray<N>;
+
+    fn bitor(mut self, rhs: ByteArray<N>) -> Self::Output {
+        self |= rhs;
+        self
+    }
+
+}
+
+impl<const N: usize> BitOrAssign<ByteArray<N>> for ByteArray<N> {
+
+    fn bitor_assign(&mut self, rhs: ByteArray<N>) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(byte, rhs)| *byte |= rhs);
+    }
+
+}
+
+impl<const N: usize> BitXor<ByteArray<N>> for ByteArray<N> {
+
+    type Output = ByteArray<N>;
+
+    fn bitxor(mut self, rhs: ByteArray<N>) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+
+}
+
+impl<const N: usize> BitXorAssign<ByteArray<N>> for ByteArray<N> {
+
+    fn bitxor_assign(&mut self, rhs: ByteArray<N>) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(byte, rhs)| *byte ^= rhs);
+    }
+
+}
+
+impl<const N: usize> Not for ByteArray<N> {
+
+    type Output = ByteArray<N>;
+
+    fn not(self) -> Self::Output {
+        ByteArray(self.0.map(|byte| !byte))
+    }
+
+}
+
+impl<const N: usize> BitStore for ByteArray<N> {
+
+    const BIT_COUNT: usize = if N <= 32 { N * 8 } else { panic!("ByteArray slots are limited to 32 bytes") };
+    const BYTE_COUNT: usize = N;
+    const ZERO: Self = ByteArray([0; N]);
+    const MAX: Self = ByteArray([u8::MAX; N]);
+
+    fn create_bit_mask(bit_index: usize) -> Self {
+        let mut mask = Self::ZERO;
+        mask.0[bit_index / 8] = 1 << (bit_index % 8);
+        mask
+    }
+
+    fn create_range_mask(start_bit: usize, bit_count: usize) -> Self {
+        let end_bit = start_bit + bit_count;
+        let mut mask = Self::ZERO;
+        for (byte_index, byte) in mask.0.iter_mut().enumerate() {
+            let byte_start = start_bit.clamp(byte_index * 8, byte_index * 8 + 8);
+            let byte_end = end_bit.clamp(byte_index * 8, byte_index * 8 + 8);
+            if byte_start < byte_end {
+                *byte = u8::create_range_mask(byte_start - byte_index * 8, byte_end - byte_start);
+            }
+        }
+
+        mask
+    }
+
+    fn count_ones(self) -> usize {
+        self.0.iter().map(|byte| byte.count_ones() as usize).sum()
+    }
+
+    fn leading_zeros(self) -> usize {
+        self.0.iter()
+            .rev()
+            .position(|byte| *byte != 0)
+            .map_or(Self::BIT_COUNT, |byte_index| byte_index * 8 + self.0[N - 1 - byte_index].leading_zeros() as usize)
+    }
+
+    fn trailing_zeros(self) -> usize {
+        self.0.iter()
+            .position(|byte| *byte != 0)
+            .map_or(Self::BIT_COUNT, |byte_index| byte_index * 8 + self.0[byte_index].trailing_zeros() as usize)
+    }
+
+    fn shift_left(self, shift: usize) -> Self {
+        let (byte_shift, bit_shift) = (shift / 8, shift % 8);
+        let mut result = Self::ZERO;
+        for byte_index in byte_shift..N {
+            let source_index = byte_index - byte_shift;
+            result.0[byte_index] = self.0[source_index] << bit_shift;
+            if (bit_shift != 0) && (source_index != 0) {
+                result.0[byte_index] |= self.0[source_index - 1] >> (8 - bit_shift);
+            }
+        }
+
+        result
+    }
+
+    fn shift_right(self, shift: usize) -> Self {
+        let (byte_shift, bit_shift) = (shift / 8, shift % 8);
+        let mut result = Self::ZERO;
+        for byte_index in 0..(N - byte_shift) {
+            let source_index = byte_index + byte_shift;
+            result.0[byte_index] = self.0[source_index] >> bit_shift;
+            if (bit_shift != 0) && (source_index + 1 != N) {
+                result.0[byte_index] |= self.0[source_index + 1] << (8 - bit_shift);
+            }
+        }
+
+        result
+    }
+
+    fn reverse_bits(self) -> Self {
+        let mut bytes = self.0.map(u8::reverse_bits);
+        bytes.reverse();
+        ByteArray(bytes)
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&self.0);
+    }
+
+    fn read_le_bytes(bytes: &[u8]) -> Self {
+        let mut value = Self::ZERO;
+        value.0.copy_from_slice(bytes);
+        value
+    }
+
+}
+
+///
+/// This trait represents an abstraction over atomic storage that contains indexable
+/// bits. Unlike [BitStore], every operation takes `&self` and is performed as an atomic
+/// read-modify-write, allowing multiple threads to modify bits in the same value.
+/// 
+pub trait AtomicBitStore: Send + Sync + Sized {
+
+    ///
+    /// The non-atomic value type stored by this type.
+    /// 
+    type Value: BitStore;
+
+    ///
+    /// Atomically loads the value stored in this type.
+    /// 
+    fn load(&self, ordering: Ordering) -> Self::Value;
+
+    ///
+    /// Atomically performs a bitwise AND of the stored value with `value`, returning the
+    /// previously stored value.
+    /// 
+    fn fetch_and(&self, value: Self::Value, ordering: Ordering) -> Self::Value;
+
+    ///
+    /// Atomically performs a bitwise OR of the stored value with `value`, returning the
+    /// previously stored value.
+    /// 
+    fn fetch_or(&self, value: Self::Value, ordering: Ordering) -> Self::Value;
+
+    ///
+    /// Atomically stores `new` if the stored value is equal to `current`. On success the
+    /// previous value is returned in `Ok`, otherwise the current stored value is returned
+    /// in `Err`.
+    /// 
+    fn compare_exchange(&self,
+                        current: Self::Value,
+                        new: Self::Value,
+                        success: Ordering,
+                        failure: Ordering) -> Result<Self::Value, Self::Value>;
+
+    ///
+    /// Atomically sets the bit at the provided index, returning `true` if the bit was
+    /// previously set. Implementations can assume that bit_index < Self::Value::BIT_COUNT.
+    /// 
+    fn fetch_set_bit(&self, bit_index: usize, ordering: Ordering) -> bool {
+        let bit_mask = Self::Value::create_bit_mask(bit_index);
+        (self.fetch_or(bit_mask, ordering) & bit_mask) != Self::Value::ZERO
+    }
+
+    ///
+    /// Atomically clears the bit at the provided index, returning `true` if the bit was
+    /// previously set. Implementations can assume that bit_index < Self::Value::BIT_COUNT.
+    /// 
+    fn fetch_clear_bit(&self, bit_index: usize, ordering: Ordering) -> bool {
+        let bit_mask = Self::Value::create_bit_mask(bit_index);
+        (self.fetch_and(!bit_mask, ordering) & bit_mask) != Self::Value::ZERO
+    }
+
+    ///
+    /// Attempts to set the bit at the provided index using a compare and exchange loop.
+    /// Returns `true` if this call changed the bit from clear to set, or `false` if the
+    /// bit was already set. Implementations can assume that bit_index < Self::Value::BIT_COUNT.
+    /// 
+    fn try_set_bit(&self, bit_index: usize, ordering: Ordering) -> bool {
+        let bit_mask = Self::Value::create_bit_mask(bit_index);
+        let failure_ordering = match ordering {
+            Ordering::Release => Ordering::Relaxed,
+            Ordering::AcqRel => Ordering::Acquire,
+            ordering => ordering
+        };
+
+        let mut current_value = self.load(failure_ordering);
+        loop {
+            if (current_value & bit_mask) != Self::Value::ZERO {
+                return false;
+            }
+
+            match self.compare_exchange(current_value, current_value | bit_mask, ordering, failure_ordering) {
+                Ok(_) => return true,
+                Err(actual_value) => current_value = actual_value
+            }
+        }
+    }
+
+}
+
+impl AtomicBitStore for AtomicU8 {
+
+    type Value = u8;
+
+    fn load(&self, ordering: Ordering) -> Self::Value {
+        Self::load(self, ordering)
+    }
+
+    fn fetch_and(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_and(self, value, ordering)
+    }
+
+    fn fetch_or(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_or(self, value, ordering)
+    }
+
+    fn compare_exchange(&self,
+                        current: Self::Value,
+                        new: Self::Value,
+                        success: Ordering,
+                        failure: Ordering) -> Result<Self::Value, Self::Value> {
+        Self::compare_exchange(self, current, new, success, failure)
+    }
+
+}
+
+impl AtomicBitStore for AtomicU16 {
+
+    type Value = u16;
+
+    fn load(&self, ordering: Ordering) -> Self::Value {
+        Self::load(self, ordering)
+    }
+
+    fn fetch_and(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_and(self, value, ordering)
+    }
+
+    fn fetch_or(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_or(self, value, ordering)
+    }
+
+    fn compare_exchange(&self,
+                        current: Self::Value,
+                        new: Self::Value,
+                        success: Ordering,
+                        failure: Ordering) -> Result<Self::Value, Self::Value> {
+        Self::compare_exchange(self, current, new, success, failure)
+    }
+
+}
+
+impl AtomicBitStore for AtomicU32 {
+
+    type Value = u32;
+
+    fn load(&self, ordering: Ordering) -> Self::Value {
+        Self::load(self, ordering)
+    }
+
+    fn fetch_and(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_and(self, value, ordering)
+    }
+
+    fn fetch_or(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_or(self, value, ordering)
+    }
+
+    fn compare_exchange(&self,
+                        current: Self::Value,
+                        new: Self::Value,
+                        success: Ordering,
+                        failure: Ordering) -> Result<Self::Value, Self::Value> {
+        Self::compare_exchange(self, current, new, success, failure)
+    }
+
+}
+
+#[cfg(target_has_atomic = "64")]
+impl AtomicBitStore for AtomicU64 {
+
+    type Value = u64;
+
+    fn load(&self, ordering: Ordering) -> Self::Value {
+        Self::load(self, ordering)
+    }
+
+    fn fetch_and(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_and(self, value, ordering)
+    }
+
+    fn fetch_or(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_or(self, value, ordering)
+    }
+
+    fn compare_exchange(&self,
+                        current: Self::Value,
+                        new: Self::Value,
+                        success: Ordering,
+                        failure: Ordering) -> Result<Self::Value, Self::Value> {
+        Self::compare_exchange(self, current, new, success, failure)
+    }
+
+}
+
+impl AtomicBitStore for AtomicUsize {
+
+    type Value = usize;
+
+    fn load(&self, ordering: Ordering) -> Self::Value {
+        Self::load(self, ordering)
+    }
+
+    fn fetch_and(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_and(self, value, ordering)
+    }
+
+    fn fetch_or(&self, value: Self::Value, ordering: Ordering) -> Self::Value {
+        Self::fetch_or(self, value, ordering)
+    }
+
+    fn compare_exchange(&self,
+                        current: Self::Value,
+                        new: Self::Value,
+                        success: Ordering,
+                        failure: Ordering) -> Result<Self::Value, Self::Value> {
+        Self::compare_exchange(self, current, new, success, failure)
+    }
+
 }
@@ -1,8 +1,222 @@
 
-use std::ops::Range;
+use crate::error::{BitmapError, OutOfBoundsError};
+
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 pub trait BitmapOpts {
 
+    ///
+    /// This routine returns the total count of set bits in this slice.
+    /// 
+    fn count_ones(&self) -> usize {
+        self.count_ones_in_range(0..self.size())
+    }
+
+    ///
+    /// This routine returns the count of set bits in the provided `range`.
+    /// 
+    fn count_ones_in_range(&self, range: Range<usize>) -> usize {
+        let mut count = 0;
+        let mut next_bit = range.start;
+        while let Some(range_start) = self.find_next_set_in_range(next_bit..range.end) {
+            let range_end =
+                self.find_next_clear_in_range((range_start + 1)..range.end)
+                    .unwrap_or(range.end);
+
+            count += range_end - range_start;
+            next_bit = range_end;
+        }
+
+        count
+    }
+
+    ///
+    /// This routine returns the total count of clear bits in this slice.
+    /// 
+    fn count_zeros(&self) -> usize {
+        self.size() - self.count_ones()
+    }
+
+    ///
+    /// This routine returns the count of clear bits in the provided `range`.
+    /// 
+    fn count_zeros_in_range(&self, range: Range<usize>) -> usize {
+        range.len() - self.count_ones_in_range(range)
+    }
+
+    ///
+    /// This routine calls `f` with the zero based index and the length of every maximal run
+    /// of clear bits in this slice, in ascending order.
+    /// 
+    fn for_each_clear_range<F: FnMut(usize, usize)>(&self, mut f: F)
+        where Self: Sized {
+
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_clear_range_from(next_bit) {
+            f(range_start, range_count);
+            next_bit = range_start + range_count;
+        }
+    }
+
+    ///
+    /// This routine calls `f` with the zero based index and the length of every maximal run
+    /// of set bits in this slice, in ascending order.
+    /// 
+    fn for_each_set_range<F: FnMut(usize, usize)>(&self, mut f: F)
+        where Self: Sized {
+
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            f(range_start, range_count);
+            next_bit = range_start + range_count;
+        }
+    }
+
+    ///
+    /// This routine returns the number of maximal runs of clear bits in this slice that are
+    /// at least `min_len` bits long.
+    /// 
+    fn count_clear_runs_at_least(&self, min_len: usize) -> usize {
+        let mut run_count = 0;
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_clear_range_from(next_bit) {
+            if range_count >= min_len {
+                run_count += 1;
+            }
+
+            next_bit = range_start + range_count;
+        }
+
+        run_count
+    }
+
+    ///
+    /// This routine returns the number of maximal runs of set bits in this slice that are
+    /// at least `min_len` bits long.
+    /// 
+    fn count_set_runs_at_least(&self, min_len: usize) -> usize {
+        let mut run_count = 0;
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            if range_count >= min_len {
+                run_count += 1;
+            }
+
+            next_bit = range_start + range_count;
+        }
+
+        run_count
+    }
+
+    ///
+    /// This routine returns `true` if every bit in this slice is set, otherwise returns false.
+    /// An empty slice is considered to have all bits set.
+    /// 
+    fn all_set(&self) -> bool {
+        self.is_full()
+    }
+
+    ///
+    /// This routine returns `true` if at least one bit in this slice is clear, otherwise returns false.
+    /// 
+    fn any_clear(&self) -> bool {
+        !self.is_full()
+    }
+
+    ///
+    /// This routine returns `true` if at least one bit in this slice is set, otherwise returns false.
+    /// 
+    fn any_set(&self) -> bool {
+        !self.is_empty()
+    }
+
+    ///
+    /// This routine returns `true` if any set bit in this slice has both of its neighbors
+    /// clear, otherwise returns false. A set bit at either end of this slice is isolated if
+    /// its single neighbor is clear.
+    /// 
+    fn has_isolated_set_bits(&self) -> bool {
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            if range_count == 1 {
+                return true;
+            }
+
+            next_bit = range_start + range_count;
+        }
+
+        false
+    }
+
+    ///
+    /// This routine returns `true` if no bit in this slice is set, otherwise returns false.
+    /// 
+    fn is_empty(&self) -> bool {
+        self.find_first_set().is_none()
+    }
+
+    ///
+    /// This routine returns `true` if every bit in this slice is set, otherwise returns false.
+    /// An empty slice is considered to be full.
+    /// 
+    fn is_full(&self) -> bool {
+        self.find_first_clear().is_none()
+    }
+
+    ///
+    /// This routine returns the count of set bits strictly before `index`. This is equivalent to
+    /// `count_ones_in_range(0..index)`, and so shares its word at a time implementation.
+    /// `index` may be equal to `size()`, in which case the total count of set bits is returned.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is greater than `size()`.
+    /// 
+    fn rank(&self, index: usize) -> usize {
+        if index > self.size() {
+            panic!("Invalid bit index ({} > {})", index, self.size());
+        }
+
+        self.count_ones_in_range(0..index)
+    }
+
+    ///
+    /// This routine returns `true` if the total count of set bits in this slice is odd,
+    /// otherwise returns false.
+    /// 
+    fn parity(&self) -> bool {
+        (self.count_ones() & 1) != 0
+    }
+
+    ///
+    /// This routine fills `out[i]` with the count of set bits in `i..(i + window)` for every
+    /// window that fits in this slice. Each count is computed incrementally from the previous
+    /// window by adding the entering bit and subtracting the leaving bit.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `window` is larger than this slice or `out.len()` is not `size() - window + 1`.
+    /// 
+    fn windowed_ones(&self, window: usize, out: &mut [usize]) {
+        if window > self.size() {
+            panic!("Invalid window size ({} > {})", window, self.size());
+
+        } else if out.len() != (self.size() - window + 1) {
+            panic!("Invalid output length ({} != {})", out.len(), self.size() - window + 1);
+        }
+
+        out[0] = self.count_ones_in_range(0..window);
+        for window_start in 1..out.len() {
+            out[window_start] =
+                out[window_start - 1] +
+                (self.get_bit(window_start + window - 1) as usize) -
+                (self.get_bit(window_start - 1) as usize);
+        }
+    }
+
     ///
     /// This routine returns the zero based index of the first clear bit in the bitmap.
     /// If this slice does not contain any clear bits, None is returned.
@@ -58,7 +272,7 @@ pub trait BitmapOpts {
     fn find_next_clear_range_from_capped(&self, starting_bit: usize, maximum_run_length: usize) -> Option<(usize, usize)> {
         self.find_next_clear_in_range(starting_bit..self.size())
             .map(|first_clear_bit| {
-                let maximum_run_length = std::cmp::min(maximum_run_length, self.size() - first_clear_bit);
+                let maximum_run_length = core::cmp::min(maximum_run_length, self.size() - first_clear_bit);
                 let next_set_bit =
                     self.find_next_set_in_range((first_clear_bit + 1)..(first_clear_bit + maximum_run_length))
                         .unwrap_or(first_clear_bit + maximum_run_length);
@@ -67,6 +281,108 @@ pub trait BitmapOpts {
             })
     }
 
+    ///
+    /// This routine returns a tuple containing the zero based index of the first clear bit within
+    /// `range` and the total count of contigious clear bits starting at that index, truncated at
+    /// `range.end`. The returned index is relative to the start of this slice, not `range`. If
+    /// `range` does not contain any clear bits, None is returned.
+    ///
+    fn find_next_clear_range_in_range(&self, range: Range<usize>) -> Option<(usize, usize)> {
+        self.find_next_clear_in_range(range.clone())
+            .map(|first_clear_bit| {
+                let next_set_bit =
+                    self.find_next_set_in_range((first_clear_bit + 1)..range.end)
+                        .unwrap_or(range.end);
+
+                (first_clear_bit, next_set_bit - first_clear_bit)
+            })
+    }
+
+    ///
+    /// This routine returns the zero based index of the start of a run of at least `len` clear
+    /// bits whose start is closest to `target`. The search proceeds outward from `target` in both
+    /// directions and stops at the first qualifying run on each side. When a clear
+    /// run is longer than `len`, the returned start is the position within that run nearest to
+    /// `target`. Ties are broken in favor of the lower index. If this slice does not contain a
+    /// clear run of at least `len` bits, None is returned.
+    ///
+    fn find_clear_run_near(&self, target: usize, len: usize) -> Option<usize> {
+        let size = self.size();
+        if len == 0 {
+            return Some(core::cmp::min(target, size));
+        }
+
+        let mut forward_start = None;
+        let mut clear_after_target = 0;
+        let mut next_bit = target;
+        while let Some((run_start, run_length)) = self.find_next_clear_range_from(next_bit) {
+            if run_start == target {
+                clear_after_target = run_length;
+            }
+
+            if run_length >= len {
+                forward_start = Some(run_start);
+                break;
+            }
+
+            next_bit = run_start + run_length;
+        }
+
+        let forward_distance = forward_start.map_or(usize::MAX, |run_start| run_start - target);
+        let mut cursor = core::cmp::min(target, size);
+        while (cursor > 0) && ((target - cursor) < forward_distance) {
+            let last_clear_bit = match self.find_prev_clear_in_range(0..cursor) {
+                Some(last_clear_bit) => last_clear_bit,
+                None => break
+            };
+
+            let run_start = self.find_prev_set_in_range(0..last_clear_bit).map_or(0, |last_set_bit| last_set_bit + 1);
+            let mut run_end = last_clear_bit + 1;
+            if run_end == target {
+                run_end += clear_after_target;
+            }
+
+            if (run_end - run_start) >= len {
+                let candidate = core::cmp::min(last_clear_bit, run_end - len);
+                if (target - candidate) <= forward_distance {
+                    return Some(candidate);
+                }
+
+                break;
+            }
+
+            cursor = run_start;
+        }
+
+        forward_start
+    }
+
+    ///
+    /// This routine returns the zero based index of the last clear bit in the slice.
+    /// If this slice does not contain any clear bits, None is returned.
+    /// 
+    fn find_last_clear(&self) -> Option<usize> {
+        self.find_prev_clear_in_range(0..self.size())
+    }
+
+    ///
+    /// This routine returns the zero based index of the last clear bit in the slice at or before
+    /// the provided `starting_bit`. If this slice does not contain any clear bits at or before
+    /// `starting_bit`, None is returned.
+    /// 
+    fn find_prev_clear_from(&self, starting_bit: usize) -> Option<usize> {
+        let ending_bit = core::cmp::min(starting_bit.saturating_add(1), self.size());
+        self.find_prev_clear_in_range(0..ending_bit)
+    }
+
+    ///
+    /// This routine returns the zero based index of the last clear bit in the provided `range`.
+    /// If the range does not contain any clear bits, None is returned.
+    /// 
+    fn find_prev_clear_in_range(&self, range: Range<usize>) -> Option<usize> {
+        range.rev().find(|bit_index| !self.get_bit(*bit_index))
+    }
+
     ///
     /// This routine returns the zero based index of the first set bit in the slice.
     /// If this slice does not contain any set bits, None is returned.
@@ -122,7 +438,7 @@ pub trait BitmapOpts {
     fn find_next_set_range_from_capped(&self, starting_bit: usize, maximum_run_length: usize) -> Option<(usize, usize)> {
         self.find_next_set_in_range(starting_bit..self.size())
             .map(|first_set_bit| {
-                let maximum_run_length = std::cmp::min(maximum_run_length, self.size() - first_set_bit);
+                let maximum_run_length = core::cmp::min(maximum_run_length, self.size() - first_set_bit);
                 let next_clear_bit =
                     self.find_next_clear_in_range((first_set_bit + 1)..(first_set_bit + maximum_run_length))
                         .unwrap_or(first_set_bit + maximum_run_length);
@@ -131,48 +447,555 @@ pub trait BitmapOpts {
             })
     }
 
+    ///
+    /// This routine returns a tuple containing the zero based index of the first set bit within
+    /// `range` and the total count of contigious set bits starting at that index, truncated at
+    /// `range.end`. The returned index is relative to the start of this slice, not `range`. If
+    /// `range` does not contain any set bits, None is returned.
+    ///
+    fn find_next_set_range_in_range(&self, range: Range<usize>) -> Option<(usize, usize)> {
+        self.find_next_set_in_range(range.clone())
+            .map(|first_set_bit| {
+                let next_clear_bit =
+                    self.find_next_clear_in_range((first_set_bit + 1)..range.end)
+                        .unwrap_or(range.end);
+
+                (first_set_bit, next_clear_bit - first_set_bit)
+            })
+    }
+
+    ///
+    /// This routine returns the zero based index of the last set bit in the slice.
+    /// If this slice does not contain any set bits, None is returned.
+    /// 
+    fn find_last_set(&self) -> Option<usize> {
+        self.find_prev_set_in_range(0..self.size())
+    }
+
+    ///
+    /// This routine returns the number of consecutive clear bits at the start of this slice,
+    /// before the first set bit. If this slice does not contain any set bits, the size of
+    /// this slice is returned.
+    /// 
+    fn trailing_zeros(&self) -> usize {
+        self.find_first_set().unwrap_or(self.size())
+    }
+
+    ///
+    /// This routine returns the number of consecutive clear bits at the end of this slice,
+    /// after the last set bit. If this slice does not contain any set bits, the size of this
+    /// slice is returned.
+    /// 
+    fn leading_zeros(&self) -> usize {
+        self.find_last_set().map_or(self.size(), |last_set_bit| self.size() - last_set_bit - 1)
+    }
+
+    ///
+    /// This routine returns the zero based index of the last set bit in the slice at or before
+    /// the provided `starting_bit`. If this slice does not contain any set bits at or before
+    /// `starting_bit`, None is returned.
+    /// 
+    fn find_prev_set_from(&self, starting_bit: usize) -> Option<usize> {
+        let ending_bit = core::cmp::min(starting_bit.saturating_add(1), self.size());
+        self.find_prev_set_in_range(0..ending_bit)
+    }
+
+    ///
+    /// This routine returns the zero based index of the last set bit in the provided `range`.
+    /// If the range does not contain any set bits, None is returned.
+    /// 
+    fn find_prev_set_in_range(&self, range: Range<usize>) -> Option<usize> {
+        range.rev().find(|bit_index| self.get_bit(*bit_index))
+    }
+
+    ///
+    /// This routine returns the zero based index of the `n`-th (zero based) set bit in this
+    /// slice. This is the inverse of [rank](BitmapOpts::rank), so `select(0)` is equivalent to
+    /// `find_first_set()`. If this slice contains `n` or fewer set bits, None is returned.
+    /// 
+    fn select(&self, n: usize) -> Option<usize> {
+        let mut remaining_count = n;
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            if remaining_count < range_count {
+                return Some(range_start + remaining_count);
+            }
+
+            remaining_count -= range_count;
+            next_bit = range_start + range_count;
+        }
+
+        None
+    }
+
+    ///
+    /// This routine calls `f` with the zero based index of each set bit in this slice in
+    /// ascending order. If `f` returns an error, iteration stops and that error is returned.
+    /// 
+    fn try_for_each_set<E, F: FnMut(usize) -> Result<(), E>>(&self, mut f: F) -> Result<(), E>
+        where Self: Sized {
+
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            for bit_index in range_start..(range_start + range_count) {
+                f(bit_index)?;
+            }
+
+            next_bit = range_start + range_count;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// This routine verifies that no bit at or beyond `capacity` is set, returning a
+    /// [BitmapError::CapacityExceeded](crate::error::BitmapError::CapacityExceeded) for the
+    /// first set bit found past `capacity`.
+    /// 
+    fn validate_capacity(&self, capacity: usize) -> Result<(), BitmapError> {
+        match self.find_next_set_from(capacity) {
+            Some(bit_index) => Err(BitmapError::CapacityExceeded { capacity, bit_index }),
+            None => Ok(())
+        }
+    }
+
     ///
     /// This routine returns `true` if the bit at the provided index is set, otherwise returns false.
     /// 
     fn get_bit(&self, bit_index: usize) -> bool;
 
+    ///
+    /// This routine returns `true` if the bit at the provided index is set, otherwise returns false.
+    /// If the provided index is past the end of this slice, an
+    /// [OutOfBoundsError](crate::error::OutOfBoundsError) is returned instead of panicking.
+    /// 
+    fn get_bit_checked(&self, bit_index: usize) -> Result<bool, OutOfBoundsError> {
+        self.check_bit_index(bit_index)?;
+        Ok(self.get_bit(bit_index))
+    }
+
+    ///
+    /// This routine returns `true` if the bit at the provided index is set, otherwise returns false.
+    /// If the provided index is past the end of this slice, `default` is returned.
+    /// 
+    fn get_bit_or(&self, bit_index: usize, default: bool) -> bool {
+        if bit_index < self.size() {
+            self.get_bit(bit_index)
+
+        } else {
+            default
+        }
+    }
+
     ///
     /// This routine returns the total size in bits of this slice.
     /// 
     fn size(&self) -> usize;
 
+    ///
+    /// This routine returns an [OutOfBoundsError](crate::error::OutOfBoundsError) if the
+    /// provided index is past the end of this slice.
+    /// 
+    fn check_bit_index(&self, bit_index: usize) -> Result<(), OutOfBoundsError> {
+        if bit_index < self.size() {
+            Ok(())
+
+        } else {
+            Err(OutOfBoundsError { bit_index, size: self.size() })
+        }
+    }
+
 }
 
 pub trait BitmapOptsMut : BitmapOpts {
 
+    ///
+    /// This routine finds the lowest clear block of `2^order` bits whose start is a multiple of
+    /// `2^order`, sets every bit in it and returns its start. If no such block is clear, None is
+    /// returned and this slice is not modified.
+    /// 
+    fn allocate_buddy(&mut self, order: usize) -> Option<usize> {
+        if order >= (usize::BITS as usize) {
+            return None;
+        }
+
+        let block_size = 1usize << order;
+        let mut next_bit = 0;
+        while let Some(first_clear_bit) = self.find_next_clear_from(next_bit) {
+            let block_start = first_clear_bit.checked_add(block_size - 1)? & !(block_size - 1);
+            let block_end = block_start.checked_add(block_size).filter(|block_end| *block_end <= self.size())?;
+            match self.find_next_set_in_range(block_start..block_end) {
+                Some(set_bit) => next_bit = set_bit + 1,
+                None => {
+                    self.set_bit_range(block_start..block_end);
+                    return Some(block_start);
+                }
+            }
+        }
+
+        None
+    }
+
+    ///
+    /// This routine finds the first run of at least `count` clear bits, sets the first `count`
+    /// bits of it and returns its start. If no clear run is long enough, None is returned and
+    /// this slice is not modified.
+    /// 
+    fn allocate_range(&mut self, count: usize) -> Option<usize> {
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_clear_range_from_capped(next_bit, count) {
+            if range_count == count {
+                self.set_bit_range(range_start..(range_start + count));
+                return Some(range_start);
+            }
+
+            next_bit = range_start + range_count;
+        }
+
+        None
+    }
+
+    ///
+    /// This routine finds the smallest run of at least `count` clear bits, sets the first
+    /// `count` bits of it and returns its start. Ties between runs of the same length are
+    /// broken in favor of the lower index, and an exact fit ends the search early. If no clear
+    /// run is long enough, None is returned and this slice is not modified.
+    /// 
+    fn allocate_range_best_fit(&mut self, count: usize) -> Option<usize> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_clear_range_from(next_bit) {
+            if (range_count >= count) && best.map_or(true, |(_, best_count)| range_count < best_count) {
+                best = Some((range_start, range_count));
+                if range_count == count {
+                    break;
+                }
+            }
+
+            next_bit = range_start + range_count;
+        }
+
+        let (range_start, _) = best?;
+        self.set_bit_range(range_start..(range_start + count));
+        Some(range_start)
+    }
+
     ///
     /// This routine clears the bit at the provided index.
     /// 
     fn clear_bit(&mut self, bit_index: usize);
 
+    ///
+    /// This routine clears every bit in this slice.
+    /// 
+    fn clear_all(&mut self) {
+        self.clear_bit_range(0..self.size());
+    }
+
+    ///
+    /// This routine clears the bit at the provided index. If the provided index is past the
+    /// end of this slice, an [OutOfBoundsError](crate::error::OutOfBoundsError) is returned
+    /// instead of panicking.
+    /// 
+    fn clear_bit_checked(&mut self, bit_index: usize) -> Result<(), OutOfBoundsError> {
+        self.check_bit_index(bit_index)?;
+        self.clear_bit(bit_index);
+        Ok(())
+    }
+
     ///
     /// This routine clears the range of bits in the provided `bit_range`.
     /// 
     fn clear_bit_range(&mut self, bit_range: Range<usize>);
 
+    ///
+    /// This routine clears the block of `2^order` bits starting at `start`, releasing a block
+    /// previously returned by [allocate_buddy](BitmapOptsMut::allocate_buddy).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `2^order` does not fit in a usize, if `start` is not a multiple of `2^order`
+    /// or if the block extends past the end of this slice.
+    /// 
+    fn free_buddy(&mut self, start: usize, order: usize) {
+        let block_size = match u32::try_from(order).ok().and_then(|order| 1usize.checked_shl(order)) {
+            Some(block_size) => block_size,
+            None => panic!("Invalid buddy order ({})", order)
+        };
+
+        if (start % block_size) != 0 {
+            panic!("Invalid buddy block start ({}) for order {}", start, order);
+        }
+
+        self.clear_bit_range(start..(start + block_size));
+    }
+
+    ///
+    /// This routine clears every bit outside of `range`, leaving the bits inside `range`
+    /// unchanged.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `range` is not within this slice.
+    /// 
+    fn keep_range(&mut self, range: Range<usize>) {
+        if range.start > range.end {
+            panic!("Invalid bit range start ({}) > end ({})", range.start, range.end);
+
+        } else if range.end > self.size() {
+            panic!("Invalid bit range [{}:{}] for bitmap of size {}", range.start, range.end, self.size());
+        }
+
+        self.clear_bit_range(range.end..self.size());
+        self.clear_bit_range(0..range.start);
+    }
+
+    ///
+    /// This routine clears every set bit whose index does not satisfy `f`. Only set bits are
+    /// visited, one run of set bits at a time, so this is efficient on sparse slices. Clear
+    /// bits are left unchanged.
+    /// 
+    fn filter_bits<F: FnMut(usize) -> bool>(&mut self, mut f: F)
+        where Self: Sized {
+
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            next_bit = range_start + range_count;
+            for bit_index in range_start..next_bit {
+                if !f(bit_index) {
+                    self.clear_bit(bit_index);
+                }
+            }
+        }
+    }
+
+    ///
+    /// This routine clears the `count` bits starting at `start`, releasing a range previously
+    /// returned by [allocate_range](BitmapOptsMut::allocate_range).
+    /// 
+    fn free_range(&mut self, start: usize, count: usize) {
+        self.clear_bit_range(start..(start + count));
+    }
+
+    ///
+    /// This routine toggles every bit in this slice.
+    /// 
+    fn invert(&mut self) {
+        self.toggle_bit_range(0..self.size());
+    }
+
     ///
     /// This routine sets the bit at the provided index.
     /// 
     fn set_bit(&mut self, bit_index: usize);
 
+    ///
+    /// This routine sets every bit in this slice.
+    /// 
+    fn set_all(&mut self) {
+        self.set_bit_range(0..self.size());
+    }
+
+    ///
+    /// This routine sets the bit at the provided index. If the provided index is past the
+    /// end of this slice, an [OutOfBoundsError](crate::error::OutOfBoundsError) is returned
+    /// instead of panicking.
+    /// 
+    fn set_bit_checked(&mut self, bit_index: usize) -> Result<(), OutOfBoundsError> {
+        self.check_bit_index(bit_index)?;
+        self.set_bit(bit_index);
+        Ok(())
+    }
+
     ///
     /// This routine sets the range of bits in the provided `bit_range`.
     /// 
     fn set_bit_range(&mut self, bit_range: Range<usize>);
-    
+
+    ///
+    /// This routine clears the first set bit in this slice and returns its index. If this
+    /// slice does not contain any set bits, None is returned.
+    /// 
+    fn steal(&mut self) -> Option<usize> {
+        self.steal_from(0)
+    }
+
+    ///
+    /// This routine clears the first set bit at or after `hint` and returns its index. If
+    /// there are no set bits at or after `hint`, the search wraps around to the start of this
+    /// slice. Spreading the hints of multiple workers scanning the same slice reduces how often
+    /// they contend for the same bits. If this slice does not contain any set bits, None is
+    /// returned.
+    /// 
+    fn steal_from(&mut self, hint: usize) -> Option<usize> {
+        let stolen_bit = self.find_next_set_from(hint)
+            .or_else(|| self.find_next_set_in_range(0..core::cmp::min(hint, self.size())))?;
+
+        self.clear_bit(stolen_bit);
+        Some(stolen_bit)
+    }
+
+    ///
+    /// This routine exchanges the values of the bits at indices `i` and `j`. Swapping a bit
+    /// with itself leaves this slice unchanged.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if either index is past the end of this slice.
+    /// 
+    fn swap_bits(&mut self, i: usize, j: usize) {
+        let (bit_i, bit_j) = (self.get_bit(i), self.get_bit(j));
+        if bit_i != bit_j {
+            self.toggle_bit(i);
+            self.toggle_bit(j);
+        }
+    }
+
     ///
     /// This routine toggles the bit at the provided index.
     /// 
     fn toggle_bit(&mut self, bit_index: usize);
 
+    ///
+    /// This routine toggles the bit at the provided index. If the provided index is past the
+    /// end of this slice, an [OutOfBoundsError](crate::error::OutOfBoundsError) is returned
+    /// instead of panicking.
+    /// 
+    fn toggle_bit_checked(&mut self, bit_index: usize) -> Result<(), OutOfBoundsError> {
+        self.check_bit_index(bit_index)?;
+        self.toggle_bit(bit_index);
+        Ok(())
+    }
+
     ///
     /// This routine toggles the range of bits in the provided `bit_range`.
     /// 
     fn toggle_bit_range(&mut self, bit_range: Range<usize>);
 
+    ///
+    /// This routine toggles the bit at every index in `indices`. Since each index is toggled
+    /// once per occurrence, duplicate indices cancel out (toggling a bit twice leaves it
+    /// unchanged).
+    /// 
+    fn toggle_bits(&mut self, indices: &[usize]) {
+        for bit_index in indices {
+            self.toggle_bit(*bit_index);
+        }
+    }
+
+}
+
+///
+/// Extends [BitmapOpts] with routines that allocate. This trait is implemented for every
+/// [BitmapOpts] type.
+/// 
+#[cfg(feature = "alloc")]
+pub trait BitmapOptsAlloc : BitmapOpts {
+
+    ///
+    /// This routine returns `true` if the clear runs of this slice could accommodate an
+    /// allocation of each size in `sizes`, otherwise returns false. Each request is placed, in
+    /// order, at the start of the first remaining clear run large enough to hold it. This is a
+    /// greedy first-fit approximation rather than an exact bin packing check, so some sets of
+    /// requests that could fit in a different order are reported as not fitting. This slice
+    /// is not modified.
+    /// 
+    fn can_satisfy(&self, sizes: &[usize]) -> bool {
+        let mut clear_runs = Vec::new();
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_clear_range_from(next_bit) {
+            clear_runs.push(range_count);
+            next_bit = range_start + range_count;
+        }
+
+        sizes.iter().all(|size| {
+            match clear_runs.iter_mut().find(|run_length| **run_length >= *size) {
+                Some(run_length) => {
+                    *run_length -= *size;
+                    true
+                },
+                None => false
+            }
+        })
+    }
+
+    ///
+    /// This routine returns a tuple containing the zero based indices of every set bit and every
+    /// clear bit in this slice, each in ascending order. Both vectors are collected in a single
+    /// pass over the slice.
+    /// 
+    fn partition_indices(&self) -> (Vec<usize>, Vec<usize>) {
+        let set_bit_count = self.count_ones();
+        let mut set_indices = Vec::with_capacity(set_bit_count);
+        let mut clear_indices = Vec::with_capacity(self.size() - set_bit_count);
+
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            clear_indices.extend(next_bit..range_start);
+            set_indices.extend(range_start..(range_start + range_count));
+            next_bit = range_start + range_count;
+        }
+
+        clear_indices.extend(next_bit..self.size());
+
+        (set_indices, clear_indices)
+    }
+
 }
+
+#[cfg(feature = "alloc")]
+impl<T: BitmapOpts + ?Sized> BitmapOptsAlloc for T { }
+
+///
+/// Extends [BitmapOptsMut] with routines that allocate. This trait is implemented for every
+/// [BitmapOptsMut] type.
+/// 
+#[cfg(feature = "alloc")]
+pub trait BitmapOptsMutAlloc : BitmapOptsMut {
+
+    ///
+    /// This routine sets every run of clear bits no longer than `max_gap` that lies between two
+    /// runs of set bits, bridging small holes. Clear runs at the start or end of this slice are
+    /// left unchanged. The short runs are collected before any bit is set.
+    /// 
+    fn fill_short_clear_runs(&mut self, max_gap: usize) {
+        let mut short_gaps = Vec::new();
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_clear_range_from(next_bit) {
+            let range_end = range_start + range_count;
+            if (range_start != 0) && (range_end != self.size()) && (range_count <= max_gap) {
+                short_gaps.push(range_start..range_end);
+            }
+
+            next_bit = range_end;
+        }
+
+        for short_gap in short_gaps {
+            self.set_bit_range(short_gap);
+        }
+    }
+
+    ///
+    /// This routine clears every maximal run of set bits shorter than `min_len`, leaving
+    /// longer runs intact. The short runs are collected before any bit is cleared.
+    /// 
+    fn remove_short_set_runs(&mut self, min_len: usize) {
+        let mut short_runs = Vec::new();
+        let mut next_bit = 0;
+        while let Some((range_start, range_count)) = self.find_next_set_range_from(next_bit) {
+            if range_count < min_len {
+                short_runs.push(range_start..(range_start + range_count));
+            }
+
+            next_bit = range_start + range_count;
+        }
+
+        for short_run in short_runs {
+            self.clear_bit_range(short_run);
+        }
+    }
+
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BitmapOptsMut + ?Sized> BitmapOptsMutAlloc for T { }